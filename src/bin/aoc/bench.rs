@@ -0,0 +1,345 @@
+//! `aoc bench`: repeated timing of parse and both parts, with allocation
+//! counts and a JSON history to spot regressions between runs.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::BTreeMap,
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use advent_of_code_2024_rust::{days::Day, Answer};
+use serde::{Deserialize, Serialize};
+
+use crate::{input_path, parse_input, read_input, select, title, DaySelection};
+
+/// A median this much slower than the previous run is reported.
+const REGRESSION_THRESHOLD: f64 = 1.10;
+
+/// Only `bench` turns counting on, so `run` and `verify` skip the counters.
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting every allocation it hands out while
+/// `COUNTING` is set.
+struct CountingAllocator;
+
+fn count_allocation(bytes: usize) {
+    if COUNTING.load(Ordering::Relaxed) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// One timed call.
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    time: Duration,
+    allocations: u64,
+    bytes: u64,
+}
+
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Sample) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = black_box(f());
+    let time = start.elapsed();
+    let sample = Sample {
+        time,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+    };
+    (result, sample)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct Stats {
+    min_ns: u64,
+    median_ns: u64,
+    p95_ns: u64,
+    /// Median allocations per call.
+    allocations: u64,
+    /// Median bytes allocated per call.
+    bytes: u64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Sample]) -> Self {
+        let mut times: Vec<u64> = samples.iter().map(|s| s.time.as_nanos() as u64).collect();
+        let mut allocations: Vec<u64> = samples.iter().map(|s| s.allocations).collect();
+        let mut bytes: Vec<u64> = samples.iter().map(|s| s.bytes).collect();
+        times.sort_unstable();
+        allocations.sort_unstable();
+        bytes.sort_unstable();
+        Stats {
+            min_ns: times[0],
+            median_ns: percentile(&times, 50),
+            p95_ns: percentile(&times, 95),
+            allocations: percentile(&allocations, 50),
+            bytes: percentile(&bytes, 50),
+        }
+    }
+
+    /// Relative change of the median against `previous`, if it regressed.
+    fn regression(&self, previous: &Stats) -> Option<f64> {
+        let ratio = self.median_ns as f64 / previous.median_ns.max(1) as f64;
+        (ratio > REGRESSION_THRESHOLD).then_some(ratio - 1.0)
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty `values`.
+fn percentile(values: &[u64], p: usize) -> u64 {
+    let rank = (values.len() * p).div_ceil(100).max(1);
+    values[rank - 1]
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DayStats {
+    parse: Stats,
+    part1: Stats,
+    /// `None` for days without a second part.
+    part2: Option<Stats>,
+}
+
+impl DayStats {
+    fn phases(&self) -> impl Iterator<Item = (&'static str, &Stats)> {
+        [("Parse", Some(&self.parse)), ("Part1", Some(&self.part1))]
+            .into_iter()
+            .chain([("Part2", self.part2.as_ref())])
+            .filter_map(|(name, stats)| Some((name, stats?)))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Record {
+    /// Seconds since the Unix epoch.
+    timestamp: u64,
+    runs: u32,
+    /// Keyed by `history_key`, so only runs on the same input are compared.
+    days: BTreeMap<String, DayStats>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+        let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&text).map_err(|e| e.to_string())
+    }
+
+    fn save(&self, path: &Path) -> Result<(), String> {
+        let text = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, text).map_err(|e| e.to_string())
+    }
+
+    /// The most recent results recorded under `key`.
+    fn latest(&self, key: &str) -> Option<&DayStats> {
+        self.records.iter().rev().find_map(|r| r.days.get(key))
+    }
+}
+
+/// The day's name and a hash of its input, e.g. `day22_v2 3f1c0a9d2b4e6f70`.
+fn history_key(day: &Day, text: &str) -> String {
+    // FNV-1a, which unlike `DefaultHasher` is stable across Rust versions.
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{} {:016x}", day.name(), hash)
+}
+
+pub fn bench(
+    selection: DaySelection,
+    variant: Option<&str>,
+    input: Option<PathBuf>,
+    runs: u32,
+    history_file: &Path,
+) -> ExitCode {
+    let Some(selected) = select(&selection, variant) else {
+        return ExitCode::FAILURE;
+    };
+    let mut history = match History::load(history_file) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!(
+                "Error: Unable to read history '{}': {}",
+                history_file.display(),
+                e
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    COUNTING.store(true, Ordering::Relaxed);
+    let mut failed = false;
+    let mut regressions = 0;
+    let mut record = Record {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        runs,
+        days: BTreeMap::new(),
+    };
+    for day in selected {
+        let input_file = input_path(&selection, input.as_deref(), day);
+        println!("{}", title(day));
+        let Some(text) = read_input(&input_file) else {
+            failed = true;
+            continue;
+        };
+        if parse_input(day, &text, &input_file).is_none() {
+            failed = true;
+            continue;
+        }
+
+        let stats = bench_day(day, &text, runs);
+        let key = history_key(day, &text);
+        let previous = history.latest(&key);
+        println!(
+            "{:<6} {:>10} {:>10} {:>10} {:>8} {:>10}",
+            "", "min", "median", "p95", "allocs", "bytes"
+        );
+        for (phase, s) in stats.phases() {
+            let regression = previous
+                .and_then(|p| p.phases().find(|&(name, _)| name == phase))
+                .and_then(|(_, old)| s.regression(old));
+            println!(
+                "{:<6} {:>10} {:>10} {:>10} {:>8} {:>10}{}",
+                phase,
+                format!("{:.2?}", Duration::from_nanos(s.min_ns)),
+                format!("{:.2?}", Duration::from_nanos(s.median_ns)),
+                format!("{:.2?}", Duration::from_nanos(s.p95_ns)),
+                s.allocations,
+                s.bytes,
+                regression.map_or(String::new(), |r| {
+                    format!("  REGRESSION +{:.0}%", r * 100.0)
+                })
+            );
+            regressions += regression.is_some() as usize;
+        }
+        record.days.insert(key, stats);
+    }
+
+    if regressions > 0 {
+        println!(
+            "{} regression(s) of more than {:.0}% against the previous run",
+            regressions,
+            (REGRESSION_THRESHOLD - 1.0) * 100.0
+        );
+    }
+    if !record.days.is_empty() {
+        history.records.push(record);
+        if let Err(e) = history.save(history_file) {
+            eprintln!(
+                "Error: Unable to write history '{}': {}",
+                history_file.display(),
+                e
+            );
+            failed = true;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Runs parse and both parts `runs` times on an input known to parse, after
+/// one untimed run to warm up caches and lazily initialised state.
+fn bench_day(day: &Day, text: &str, runs: u32) -> DayStats {
+    let parsed = day.solver.parse(text).expect("input parsed before");
+    black_box(day.solver.part1(parsed.as_ref()));
+    black_box(day.solver.part2(parsed.as_ref()));
+
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+    let mut has_part2 = true;
+    for _ in 0..runs {
+        let (parsed, sample) = measure(|| day.solver.parse(text).expect("input parsed before"));
+        parse.push(sample);
+        part1.push(measure(|| day.solver.part1(parsed.as_ref())).1);
+        let (answer, sample) = measure(|| day.solver.part2(parsed.as_ref()));
+        has_part2 = answer != Answer::Unsolved;
+        part2.push(sample);
+    }
+    DayStats {
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: has_part2.then(|| Stats::from_samples(&part2)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_test() {
+        let samples: Vec<Sample> = (1..=20)
+            .map(|n| Sample {
+                time: Duration::from_nanos(n * 100),
+                allocations: n % 3,
+                bytes: 64,
+            })
+            .collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(
+            (stats.min_ns, stats.median_ns, stats.p95_ns),
+            (100, 1000, 1900)
+        );
+        assert_eq!((stats.allocations, stats.bytes), (1, 64));
+
+        let slower = Stats {
+            median_ns: 1200,
+            ..stats
+        };
+        assert_eq!(stats.regression(&slower), None);
+        assert!(slower.regression(&stats).is_some_and(|r| r > 0.19));
+        let noise = Stats {
+            median_ns: 1050,
+            ..stats
+        };
+        assert_eq!(noise.regression(&stats), None);
+    }
+
+    #[test]
+    fn history_key_test() {
+        let day = &advent_of_code_2024_rust::days::DAYS[0];
+        assert_eq!(history_key(day, "1 2"), history_key(day, "1 2"));
+        assert_ne!(history_key(day, "1 2"), history_key(day, "1 3"));
+        assert!(history_key(day, "").starts_with("day01 "));
+    }
+}
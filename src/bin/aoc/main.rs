@@ -0,0 +1,220 @@
+use std::{
+    any::Any,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Instant,
+};
+
+use advent_of_code_2024_rust::{
+    days::{self, Day},
+    Answer,
+};
+use clap::{Parser, Subcommand};
+
+mod bench;
+mod verify;
+
+/// Runs the Advent of Code 2024 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with `all`.
+    Run {
+        /// Day number or `all`.
+        day: DaySelection,
+        /// Alternative implementation, e.g. `v2`.
+        #[arg(long)]
+        variant: Option<String>,
+        /// Only run this part.
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Input file, or the directory holding inputNN.txt files with `all`.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Time parse, part 1 and part 2 over several runs and record the results.
+    Bench {
+        /// Day number or `all`.
+        day: DaySelection,
+        /// Alternative implementation, e.g. `v2`.
+        #[arg(long)]
+        variant: Option<String>,
+        /// Input file, or the directory holding inputNN.txt files with `all`.
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Number of timed runs per day.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// JSON file that keeps earlier results to compare against.
+        #[arg(long, default_value = "bench_history.json")]
+        history: PathBuf,
+    },
+    /// Check every day listed in the answers file against its real input.
+    Verify {
+        /// TOML file with `[[answer]]` tables of day, part, answer and variant.
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Directory holding the inputNN.txt files.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone)]
+enum DaySelection {
+    All,
+    Day(u32),
+}
+
+impl std::str::FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        match s.parse::<u32>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(DaySelection::Day(day)),
+            _ => Err(format!(
+                "expected a day between 1 and 25 or `all`, got '{}'",
+                s
+            )),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    env_logger::init();
+
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            variant,
+            part,
+            input,
+        } => run(day, variant.as_deref(), part, input),
+        Command::Bench {
+            day,
+            variant,
+            input,
+            runs,
+            history,
+        } => bench::bench(day, variant.as_deref(), input, runs, &history),
+        Command::Verify { answers, input } => verify::verify(&answers, input.as_deref()),
+    }
+}
+
+/// The days to run, or `None` after reporting that the day doesn't exist.
+fn select(selection: &DaySelection, variant: Option<&str>) -> Option<Vec<&'static Day>> {
+    match *selection {
+        DaySelection::All => Some(days::DAYS.iter().filter(|d| d.variant == variant).collect()),
+        DaySelection::Day(day) => match days::find(day, variant) {
+            Some(d) => Some(vec![d]),
+            None => {
+                eprintln!(
+                    "Error: Day {} {}is not implemented",
+                    day,
+                    variant
+                        .map(|v| format!("variant '{}' ", v))
+                        .unwrap_or_default()
+                );
+                None
+            }
+        },
+    }
+}
+
+fn input_path(selection: &DaySelection, input: Option<&Path>, day: &Day) -> PathBuf {
+    match (selection, input) {
+        (DaySelection::Day(_), Some(path)) => path.to_path_buf(),
+        (DaySelection::All, Some(dir)) => dir.join(day.input_file()),
+        (_, None) => PathBuf::from(day.input_file()),
+    }
+}
+
+fn title(day: &Day) -> String {
+    format!(
+        "Day {:02}{}",
+        day.day,
+        day.variant.map(|v| format!(" ({})", v)).unwrap_or_default()
+    )
+}
+
+fn read_input(input_file: &Path) -> Option<String> {
+    fs::read_to_string(input_file)
+        .map_err(|_| eprintln!("Error: Unable to read file '{}'", input_file.display()))
+        .ok()
+}
+
+fn parse_input(day: &Day, text: &str, input_file: &Path) -> Option<Box<dyn Any>> {
+    day.solver
+        .parse(text)
+        .map_err(|e| {
+            let e = e.with_day(day.day);
+            eprintln!(
+                "Error: Unable to parse '{}': {}\n{}",
+                input_file.display(),
+                e,
+                e.snippet()
+            );
+        })
+        .ok()
+}
+
+fn run(
+    selection: DaySelection,
+    variant: Option<&str>,
+    part: Option<u32>,
+    input: Option<PathBuf>,
+) -> ExitCode {
+    let Some(selected) = select(&selection, variant) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut failed = false;
+    for day in selected {
+        let input_file = input_path(&selection, input.as_deref(), day);
+
+        println!("{}", title(day));
+        let Some(text) = read_input(&input_file) else {
+            failed = true;
+            continue;
+        };
+
+        let start = Instant::now();
+        let Some(parsed) = parse_input(day, &text, &input_file) else {
+            failed = true;
+            continue;
+        };
+        println!("Parse: {:.2?}", start.elapsed());
+
+        if part.is_none_or(|p| p == 1) {
+            run_part(1, || day.solver.part1(parsed.as_ref()));
+        }
+        if part.is_none_or(|p| p == 2) {
+            run_part(2, || day.solver.part2(parsed.as_ref()));
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_part(n: u32, solve: impl FnOnce() -> Answer) {
+    let start = Instant::now();
+    let answer = solve();
+    if answer != Answer::Unsolved {
+        println!("Part{}: {} ({:.2?})", n, answer, start.elapsed());
+    }
+}
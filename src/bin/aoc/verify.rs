@@ -0,0 +1,182 @@
+//! `aoc verify`: solves every day with known answers and compares them to
+//! the answers already submitted, listed in answers.toml.
+
+use std::{fmt, fs, path::Path, process::ExitCode};
+
+use advent_of_code_2024_rust::days::{self, Day};
+use serde::Deserialize;
+
+use crate::{parse_input, read_input, title};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Int(n) => write!(f, "{}", n),
+            Expected::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// One `[[answer]]` table.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    day: u32,
+    part: u32,
+    answer: Expected,
+    /// Only for this variant. Entries without one apply to every variant
+    /// that has no entry of its own.
+    variant: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Answers {
+    #[serde(default)]
+    answer: Vec<Entry>,
+}
+
+impl Answers {
+    fn parse(text: &str) -> Result<Self, String> {
+        let answers: Answers = toml::from_str(text).map_err(|e| e.to_string())?;
+        for e in &answers.answer {
+            if !(1..=25).contains(&e.day) || !(1..=2).contains(&e.part) {
+                return Err(format!("no day {} part {}", e.day, e.part));
+            }
+        }
+        Ok(answers)
+    }
+
+    fn expected(&self, day: &Day, part: u32) -> Option<&Expected> {
+        let matching = |variant: Option<&str>| {
+            self.answer
+                .iter()
+                .find(|e| e.day == day.day && e.part == part && e.variant.as_deref() == variant)
+        };
+        day.variant
+            .and_then(|v| matching(Some(v)))
+            .or_else(|| matching(None))
+            .map(|e| &e.answer)
+    }
+}
+
+pub fn verify(answers_file: &Path, input_dir: Option<&Path>) -> ExitCode {
+    let answers = match fs::read_to_string(answers_file)
+        .map_err(|e| e.to_string())
+        .and_then(|text| Answers::parse(&text))
+    {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!(
+                "Error: Unable to read answers '{}': {}",
+                answers_file.display(),
+                e
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "{:<12} {:<5} {:<20} {:<20} Result",
+        "Day", "Part", "Expected", "Actual"
+    );
+    let (mut passed, mut failed) = (0, 0);
+    for day in days::DAYS {
+        let expected = [1, 2].map(|part| answers.expected(day, part));
+        if expected.iter().all(Option::is_none) {
+            continue;
+        }
+
+        let input_file = input_dir.unwrap_or(Path::new("")).join(day.input_file());
+        let parsed = read_input(&input_file).and_then(|text| parse_input(day, &text, &input_file));
+        for (part, expected) in (1..).zip(expected) {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let actual = match &parsed {
+                Some(parsed) if part == 1 => day.solver.part1(parsed.as_ref()).to_string(),
+                Some(parsed) => day.solver.part2(parsed.as_ref()).to_string(),
+                None => "error".to_string(),
+            };
+            let ok = actual == expected.to_string();
+            if ok {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+            println!(
+                "{:<12} {:<5} {:<20} {:<20} {}",
+                title(day),
+                part,
+                expected.to_string(),
+                actual,
+                if ok { "ok" } else { "FAIL" }
+            );
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_test() {
+        let answers = Answers::parse(
+            r#"
+            [[answer]]
+            day = 22
+            part = 1
+            answer = 37327623
+
+            [[answer]]
+            day = 22
+            part = 2
+            answer = 23
+
+            [[answer]]
+            day = 22
+            part = 2
+            variant = "v2"
+            answer = 24
+
+            [[answer]]
+            day = 23
+            part = 2
+            answer = "co,de,ka,ta"
+            "#,
+        )
+        .unwrap();
+
+        let day22 = days::find(22, None).unwrap();
+        let day22_v2 = days::find(22, Some("v2")).unwrap();
+        let day23 = days::find(23, None).unwrap();
+        assert_eq!(answers.expected(day22, 2), Some(&Expected::Int(23)));
+        assert_eq!(answers.expected(day22_v2, 2), Some(&Expected::Int(24)));
+        assert_eq!(
+            answers.expected(day22_v2, 1),
+            Some(&Expected::Int(37327623))
+        );
+        assert_eq!(answers.expected(day23, 1), None);
+        assert_eq!(
+            answers.expected(day23, 2).map(|e| e.to_string()),
+            Some("co,de,ka,ta".to_string())
+        );
+
+        assert!(Answers::parse("[[answer]]\nday = 26\npart = 1\nanswer = 1").is_err());
+        assert!(Answers::parse("[[answer]]\nday = 1\npart = 1").is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where the sequence `x, f(x), f(f(x)), ...` starts repeating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    /// Steps before the first state on the cycle.
+    pub start: usize,
+    pub period: usize,
+    /// The state reached after `start` steps.
+    pub state: S,
+}
+
+impl<S> Cycle<S> {
+    /// Step in `0..start + period` that reaches the same state as step `n`.
+    pub fn equivalent_step(&self, n: u64) -> usize {
+        let start = self.start as u64;
+        if n < start {
+            n as usize
+        } else {
+            (start + (n - start) % self.period as u64) as usize
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. Needs no memory beyond two states, but runs
+/// `step` roughly three times per step of the sequence.
+///
+/// Never returns if the sequence does not repeat.
+pub fn floyd<S: Clone + Eq>(x0: S, step: impl Fn(&S) -> S) -> Cycle<S> {
+    let mut tortoise = step(&x0);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+
+    let mut start = 0;
+    tortoise = x0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+    Cycle {
+        start,
+        period,
+        state: tortoise,
+    }
+}
+
+/// Brent's algorithm. Like `floyd` it keeps only two states, but usually
+/// calls `step` fewer times.
+///
+/// Never returns if the sequence does not repeat.
+pub fn brent<S: Clone + Eq>(x0: S, step: impl Fn(&S) -> S) -> Cycle<S> {
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = step(&x0);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Start the hare one period ahead, then move both until they meet.
+    let mut start = 0;
+    tortoise = x0.clone();
+    hare = x0;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    Cycle {
+        start,
+        period,
+        state: tortoise,
+    }
+}
+
+/// Remembers every state, so `step` runs only once per state. Stops early
+/// with `None` when no state repeats within `limit` steps.
+pub fn find_hashed<S: Clone + Eq + Hash>(
+    x0: S,
+    step: impl Fn(&S) -> S,
+    limit: usize,
+) -> Option<Cycle<S>> {
+    let mut seen = HashMap::new();
+    let mut x = x0;
+    for i in 0..=limit {
+        if let Some(&start) = seen.get(&x) {
+            return Some(Cycle {
+                start,
+                period: i - start,
+                state: x,
+            });
+        }
+        let next = step(&x);
+        seen.insert(x, i);
+        x = next;
+    }
+    None
+}
+
+/// The state after `n` steps. Once a state repeats, the rest of the way is
+/// skipped using the cycle, so `n` can be as large as 10^12.
+///
+/// Detects the cycle like `brent`, keeping only two states, so long lead-ins
+/// and periods cost time but no memory. A sequence that does not repeat
+/// within `n` steps is simply run for all of them.
+pub fn fast_forward<S: Clone + Eq>(x0: S, step: impl Fn(&S) -> S, n: u64) -> S {
+    let mut power = 1;
+    let mut period = 0;
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for i in 1..=n {
+        if period == power {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        if hare == tortoise {
+            // The hare, `i` steps in, repeats a state, so it is on the cycle.
+            for _ in 0..(n - i) % period {
+                hare = step(&hare);
+            }
+            return hare;
+        }
+    }
+    hare
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2, 4, 16, 256, 536, ... mod 1000 comes back to 16 after 20 steps.
+    fn square(x: &u64) -> u64 {
+        x * x % 1000
+    }
+
+    #[test]
+    fn cycle_test() {
+        let expected = find_hashed(2, square, 1000).unwrap();
+        assert_eq!(floyd(2, square), expected);
+        assert_eq!(brent(2, square), expected);
+        assert_eq!((expected.start, expected.period), (2, 20));
+        assert_eq!(expected.state, 16);
+        assert_eq!(find_hashed(2, square, 5), None);
+
+        let fixed_point = brent(0, |&x: &u8| x.saturating_sub(1));
+        assert_eq!((fixed_point.start, fixed_point.period), (0, 1));
+    }
+
+    #[test]
+    fn fast_forward_test() {
+        let mut x = 2;
+        for n in 0..100 {
+            assert_eq!(fast_forward(2, square, n), x);
+            x = square(&x);
+        }
+        let cycle = brent(2, square);
+        let far = 1_000_000_000_000;
+        assert_eq!(
+            fast_forward(2, square, far),
+            fast_forward(2, square, cycle.equivalent_step(far) as u64)
+        );
+
+        // Never repeats, so all the steps are run.
+        assert_eq!(fast_forward(0u64, |&x| x + 1, 1000), 1000);
+        assert_eq!(fast_forward(7u64, |&x| x + 1, 0), 7);
+    }
+}
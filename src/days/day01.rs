@@ -0,0 +1,91 @@
+use crate::{error::ParseError, parse::lines, Answer, Solution};
+
+pub struct Day01;
+
+type Lists = (Vec<i64>, Vec<i64>);
+
+fn parse(input: &str) -> Result<Lists, ParseError> {
+    let mut l1 = Vec::new();
+    let mut l2 = Vec::new();
+    for line in lines(input) {
+        let [n1, n2] = line.ints_exact()?;
+        l1.push(n1);
+        l2.push(n2);
+    }
+    Ok((l1, l2))
+}
+
+fn solve_p1(lists: &Lists) -> i64 {
+    let mut total = 0i64;
+    let (mut l1, mut l2) = lists.clone();
+
+    while !l1.is_empty() {
+        let n1 = l1.iter().min().unwrap();
+        let n2 = l2.iter().min().unwrap();
+        let d = (n1 - n2).abs();
+        total += d;
+        let i1 = l1.iter().position(|x| *x == *n1).unwrap();
+        l1.remove(i1);
+        let i2 = l2.iter().position(|x| *x == *n2).unwrap();
+        l2.remove(i2);
+    }
+
+    total
+}
+
+fn solve_p2(lists: &Lists) -> i64 {
+    let mut total = 0i64;
+    let (l1, l2) = lists;
+
+    for &n1 in l1 {
+        let count = l2.iter().filter(|&&x| x == n1).count() as i64;
+        total += count * n1;
+    }
+
+    total
+}
+
+impl Solution for Day01 {
+    type Parsed = Lists;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(lists: &Self::Parsed) -> Answer {
+        solve_p1(lists).into()
+    }
+
+    fn part2(lists: &Self::Parsed) -> Answer {
+        solve_p2(lists).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test1() {
+        let input = r#"3   4
+4   3
+2   5
+1   3
+3   9
+3   3"#;
+
+        assert_eq!(solve_p1(&parse(input).unwrap()), 11);
+    }
+
+    #[test]
+    fn p2_test1() {
+        let input = r#"3   4
+4   3
+2   5
+1   3
+3   9
+3   3"#;
+
+        assert_eq!(solve_p2(&parse(input).unwrap()), 31);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{error::ParseError, parse::lines, Answer, Solution};
+
+pub struct Day02;
+
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut reports = Vec::new();
+    for line in lines(input) {
+        let text = line.text;
+        if let Some(c) = text.find(|c: char| !c.is_ascii_digit() && !c.is_whitespace()) {
+            return Err(line.error_at(&text[c..], "expected levels separated by spaces"));
+        }
+        reports.push(line.ints()?);
+    }
+    Ok(reports)
+}
+
+fn solve_p1(reports: &[Vec<i64>]) -> i64 {
+    let mut total = 0;
+    for ints in reports {
+        if is_safe_sequence(ints) {
+            total += 1;
+        }
+    }
+
+    total
+}
+
+fn is_safe_sequence(ints: &[i64]) -> bool {
+    let safe = {
+        let mut safe = true;
+        for i in 1..ints.len() {
+            let diff = ints[i - 1] - ints[i];
+            if (1..=3).contains(&diff) {
+            } else {
+                safe = false;
+                break;
+            }
+        }
+        safe
+    };
+
+    if safe {
+        return true;
+    }
+
+    let safe = {
+        let mut safe = true;
+        for i in 1..ints.len() {
+            let diff = ints[i] - ints[i - 1];
+            if (1..=3).contains(&diff) {
+            } else {
+                safe = false;
+                break;
+            }
+        }
+        safe
+    };
+
+    if safe {
+        return true;
+    }
+
+    false
+}
+
+fn solve_p2(reports: &[Vec<i64>]) -> i64 {
+    let mut total = 0;
+    for ints in reports {
+        for i in 0..ints.len() {
+            let mut seq = ints.clone();
+            seq.remove(i);
+            if is_safe_sequence(&seq) {
+                total += 1;
+                break;
+            }
+        }
+    }
+
+    total
+}
+
+impl Solution for Day02 {
+    type Parsed = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(reports: &Self::Parsed) -> Answer {
+        solve_p1(reports).into()
+    }
+
+    fn part2(reports: &Self::Parsed) -> Answer {
+        solve_p2(reports).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"7 6 4 2 1
+    1 2 7 8 9
+    9 7 6 2 1
+    1 3 2 4 5
+    8 6 4 4 1
+    1 3 6 7 9"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 2);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 4);
+    }
+}
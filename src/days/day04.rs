@@ -0,0 +1,102 @@
+use crate::{
+    error::ParseError,
+    map::{parse_map, Map},
+    vec2::Vec2i,
+    Answer, Solution,
+};
+
+pub struct Day04;
+
+/// The puzzle in all four orientations, so that searching rightwards and
+/// diagonally down-right in each covers all eight directions.
+fn rotations(m: &Map) -> [Map; 4] {
+    [m.clone(), m.rotate_90(), m.rotate_180(), m.rotate_270()]
+}
+
+/// Whether each `(offset, char)` of `pattern` matches around `p`.
+fn matches_at(m: &Map, p: Vec2i, pattern: impl IntoIterator<Item = (Vec2i, char)>) -> bool {
+    pattern.into_iter().all(|(d, c)| m.read(p + d) == Some(c))
+}
+
+//---------
+// Part 1
+
+const WORD: &str = "XMAS";
+
+fn count_word(m: &Map, dir: Vec2i) -> usize {
+    m.positions()
+        .filter(|&p| matches_at(m, p, (0..).map(|i| dir * i).zip(WORD.chars())))
+        .count()
+}
+
+fn solve_p1(m: &Map) -> usize {
+    rotations(m)
+        .iter()
+        .map(|r| count_word(r, Vec2i::new(1, 0)) + count_word(r, Vec2i::new(1, 1)))
+        .sum()
+}
+
+//---------
+// Part 2
+
+/// Both MAS diagonals with the M's on top; rotations give the other three.
+const X_MAS: [((i32, i32), char); 5] = [
+    ((-1, -1), 'M'),
+    ((1, -1), 'M'),
+    ((0, 0), 'A'),
+    ((-1, 1), 'S'),
+    ((1, 1), 'S'),
+];
+
+fn solve_p2(m: &Map) -> usize {
+    rotations(m)
+        .iter()
+        .map(|r| {
+            r.positions()
+                .filter(|&p| matches_at(r, p, X_MAS.map(|(d, c)| (Vec2i::from(d), c))))
+                .count()
+        })
+        .sum()
+}
+
+impl Solution for Day04 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(m: &Self::Parsed) -> Answer {
+        solve_p1(m).into()
+    }
+
+    fn part2(m: &Self::Parsed) -> Answer {
+        solve_p2(m).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EXAMPLE1: &str = r#"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse_map(INPUT_EXAMPLE1).unwrap()), 18);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse_map(INPUT_EXAMPLE1).unwrap()), 9);
+    }
+}
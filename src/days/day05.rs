@@ -0,0 +1,169 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use crate::{
+    error::{parse_at, ParseError},
+    parse::sections_exact,
+    Answer, Solution,
+};
+
+pub struct Day05;
+
+pub struct PrintQueue {
+    rules: Vec<(i64, i64)>,
+    updates: Vec<Vec<i64>>,
+}
+
+fn parse(input: &str) -> Result<PrintQueue, ParseError> {
+    let [rule_lines, update_lines] = sections_exact(input)?;
+    let mut rules = Vec::new();
+    for line in rule_lines {
+        let (l, r) = line.key_value("|")?;
+        rules.push((
+            parse_at(line.index, line.text, l)?,
+            parse_at(line.index, line.text, r)?,
+        ));
+    }
+    let mut updates = Vec::new();
+    for line in update_lines {
+        let pages: Vec<i64> = line.ints()?;
+        if pages.is_empty() {
+            return Err(line.error("expected comma separated page numbers"));
+        }
+        // Both parts score an update by its middle page.
+        if pages.len().is_multiple_of(2) {
+            return Err(line.error("expected an odd number of pages"));
+        }
+        updates.push(pages);
+    }
+    Ok(PrintQueue { rules, updates })
+}
+
+fn check_rule(u: i64, v: i64, rules: &[(i64, i64)]) -> bool {
+    for (l, r) in rules {
+        if *l == v && *r == u {
+            return false;
+        }
+    }
+    true
+}
+
+fn is_valid_update(update: &[i64], rules: &[(i64, i64)]) -> bool {
+    for i in 0..update.len() {
+        let u = update[i];
+        for &v in &update[i + 1..] {
+            if !check_rule(u, v, rules) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn solve_p1(queue: &PrintQueue) -> i64 {
+    let mut total = 0i64;
+    for update in &queue.updates {
+        if is_valid_update(update, &queue.rules) {
+            total += update[update.len() / 2];
+        }
+    }
+
+    total
+}
+
+fn reorder_update(update: &mut [i64], rules: &HashSet<(i64, i64)>) {
+    update.sort_by(|&a, &b| {
+        if a == b {
+            Ordering::Equal
+        } else if rules.contains(&(a, b)) {
+            Ordering::Less
+        } else if rules.contains(&(b, a)) {
+            Ordering::Greater
+        } else {
+            panic!("Invalid rules found while reordering update: {:?}", rules)
+        }
+    });
+}
+
+fn solve_p2(queue: &PrintQueue) -> i64 {
+    let mut total = 0i64;
+    let rules2: HashSet<_> = queue.rules.iter().copied().collect();
+    for update in &queue.updates {
+        if !is_valid_update(update, &queue.rules) {
+            let mut update = update.clone();
+            reorder_update(&mut update, &rules2);
+            total += update[update.len() / 2];
+        }
+    }
+
+    total
+}
+
+impl Solution for Day05 {
+    type Parsed = PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(queue: &Self::Parsed) -> Answer {
+        solve_p1(queue).into()
+    }
+
+    fn part2(queue: &Self::Parsed) -> Answer {
+        solve_p2(queue).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 143);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 123);
+    }
+
+    #[test]
+    fn even_update_test() {
+        for input in ["47|53\n\n47,53\n", "47|53\n\n,\n"] {
+            let Err(e) = parse(input) else {
+                panic!("expected a parse error");
+            };
+            assert_eq!(e.line, 3);
+        }
+    }
+}
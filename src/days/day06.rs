@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use crate::{
+    cycle::brent,
+    dir::Dir4,
+    error::ParseError,
+    map::{parse_map, Map},
+    vec2::Vec2i,
+    Answer, Solution,
+};
+
+pub struct Day06;
+
+fn parse(input: &str) -> Result<(Map, Vec2i), ParseError> {
+    let mut map = parse_map(input)?;
+    let start_pos = map.take_marker('^', '.')?;
+    Ok((map, start_pos))
+}
+
+fn solve_p1((map, start_pos): &(Map, Vec2i)) -> i64 {
+    let mut p = *start_pos;
+    let mut d = Dir4::Up;
+
+    let mut visited: HashSet<Vec2i> = HashSet::new();
+
+    while map.inside(&p) {
+        visited.insert(p);
+
+        let p2 = p.step(d, 1);
+        if map.read(p2) == Some('#') {
+            d = d.turn_right();
+        } else {
+            p = p2;
+        }
+    }
+
+    visited.len() as i64
+}
+
+/// Guard position and facing, or `None` once the guard has left the map.
+type Guard = Option<(Vec2i, Dir4)>;
+
+fn patrol_step(map: &Map, obstruction: Vec2i, guard: &Guard) -> Guard {
+    let (p, d) = (*guard)?;
+    let p2 = p.step(d, 1);
+    if map.read(p2) == Some('#') || p2 == obstruction {
+        Some((p, d.turn_right()))
+    } else {
+        map.inside(&p2).then_some((p2, d))
+    }
+}
+
+fn solve_p2((map, start_pos): &(Map, Vec2i)) -> i64 {
+    let start: Guard = Some((*start_pos, Dir4::Up));
+
+    // Leaving the map ends in the `None` fixed point, so the guard loops
+    // exactly when the cycle is anywhere else.
+    map.find_all('.')
+        .filter(|&obstruction| obstruction != *start_pos)
+        .filter(|&obstruction| {
+            brent(start, |guard| patrol_step(map, obstruction, guard))
+                .state
+                .is_some()
+        })
+        .count() as i64
+}
+
+impl Solution for Day06 {
+    type Parsed = (Map, Vec2i);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(lab: &Self::Parsed) -> Answer {
+        solve_p1(lab).into()
+    }
+
+    fn part2(lab: &Self::Parsed) -> Answer {
+        solve_p2(lab).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 41);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 6);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{error::ParseError, parse::lines, Answer, Solution};
+
+pub struct Day07;
+
+/// Each equation is its test value followed by the operands.
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    let mut equations = Vec::new();
+    for line in lines(input) {
+        let ints = line.ints()?;
+        if ints.len() < 2 {
+            return Err(line.error("expected a test value and operands"));
+        }
+        equations.push(ints);
+    }
+    Ok(equations)
+}
+
+fn solve_p1(equations: &[Vec<i64>]) -> i64 {
+    let mut total = 0i64;
+    for ints in equations {
+        let test_value = ints[0];
+        let mut variants = vec![ints[1]];
+        for &x in &ints[2..] {
+            let mut variants_new = Vec::new();
+            for variant in &variants {
+                variants_new.push(variant + x);
+                variants_new.push(variant * x);
+            }
+            variants = variants_new;
+        }
+
+        if variants.contains(&test_value) {
+            total += test_value;
+        }
+    }
+
+    total
+}
+
+fn solve_p2(equations: &[Vec<i64>]) -> i64 {
+    let mut total = 0i64;
+    for ints in equations {
+        let test_value = ints[0];
+        let mut variants = vec![ints[1]];
+        for &x in &ints[2..] {
+            let mut variants_new = Vec::new();
+            for variant in &variants {
+                variants_new.push(variant + x);
+
+                variants_new.push(variant * x);
+
+                let v3 = variant.to_string() + &x.to_string();
+                let v3 = v3.parse::<i64>().unwrap();
+                variants_new.push(v3);
+            }
+            variants = variants_new;
+        }
+
+        if variants.contains(&test_value) {
+            total += test_value;
+        }
+    }
+
+    total
+}
+
+impl Solution for Day07 {
+    type Parsed = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(equations: &Self::Parsed) -> Answer {
+        solve_p1(equations).into()
+    }
+
+    fn part2(equations: &Self::Parsed) -> Answer {
+        solve_p2(equations).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 3749);
+    }
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 11387);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::ParseError,
+    map::{parse_map, Map},
+    vec2::Vec2i,
+    Answer, Solution,
+};
+
+pub struct Day08;
+
+fn parse(input: &str) -> Result<Map, ParseError> {
+    parse_map(input)
+}
+
+/// Antenna positions grouped by frequency.
+fn antennas(map: &Map) -> HashMap<char, Vec<Vec2i>> {
+    let mut antennas = map.positions_by_char();
+    antennas.remove(&'.');
+    antennas
+}
+
+fn solve_p1(map: &Map) -> i64 {
+    let mut antinodes = HashSet::new();
+    for ps in antennas(map).values() {
+        for (i, &p1) in ps.iter().enumerate() {
+            for &p2 in &ps[i + 1..] {
+                let dist = p2 - p1;
+                for a in [p1 - dist, p2 + dist] {
+                    if map.inside(&a) {
+                        antinodes.insert(a);
+                    }
+                }
+            }
+        }
+    }
+
+    antinodes.len() as i64
+}
+
+fn solve_p2(map: &Map) -> i64 {
+    let mut antinodes = HashSet::new();
+    for ps in antennas(map).values() {
+        for (i, &p1) in ps.iter().enumerate() {
+            for &p2 in &ps[i + 1..] {
+                let dist = p2 - p1;
+                let mut p = p1;
+                while map.inside(&p) {
+                    antinodes.insert(p);
+                    p -= dist;
+                }
+                let mut p = p2;
+                while map.inside(&p) {
+                    antinodes.insert(p);
+                    p += dist;
+                }
+            }
+        }
+    }
+
+    antinodes.len() as i64
+}
+
+impl Solution for Day08 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Answer {
+        solve_p1(map).into()
+    }
+
+    fn part2(map: &Self::Parsed) -> Answer {
+        solve_p2(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 14);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 34);
+    }
+}
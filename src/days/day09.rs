@@ -0,0 +1,153 @@
+use crate::{error::ParseError, Answer, Solution};
+
+pub struct Day09;
+
+/// The disk map: alternating file and free space lengths.
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = input.trim();
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| ParseError::new(0, i, line, "expected a digit"))
+        })
+        .collect()
+}
+
+fn solve_p1(blocks: &[usize]) -> usize {
+    let mut file = true;
+    let mut id = 0;
+    let mut disk: Vec<isize> = Vec::new();
+    for &len in blocks {
+        if file {
+            for _ in 0..len {
+                disk.push(id);
+            }
+            id += 1;
+        } else {
+            for _ in 0..len {
+                disk.push(-1);
+            }
+        }
+        file = !file;
+    }
+
+    let mut first_free = disk.iter().position(|&x| x == -1).unwrap();
+    let mut last_used = disk.iter().rposition(|&x| x != -1).unwrap();
+
+    while first_free < last_used {
+        let last = disk[last_used];
+        disk[first_free] = last;
+        disk[last_used] = -1;
+
+        while disk[last_used] == -1 {
+            last_used -= 1;
+        }
+
+        while disk[first_free] != -1 {
+            first_free += 1;
+        }
+    }
+
+    let mut checksum: usize = 0;
+    for (i, &id) in disk.iter().enumerate() {
+        if id != -1 {
+            checksum += i * (id as usize);
+        }
+    }
+
+    checksum
+}
+
+fn solve_p2(blocks: &[usize]) -> usize {
+    let mut file = true;
+    let mut id = 0;
+    let mut files: Vec<(isize, usize, usize)> = Vec::new(); // (id, pos, len), (-1, pos, len) is free
+    let mut pos = 0;
+    for &len in blocks {
+        if file {
+            if len > 0 {
+                files.push((id, pos, len));
+                pos += len;
+            }
+            id += 1;
+        } else {
+            if len > 0 {
+                files.push((-1, pos, len));
+                pos += len;
+            }
+        }
+        file = !file;
+    }
+
+    id -= 1;
+
+    while id >= 0 {
+        let file_pos = files.iter().position(|(fid, _, _)| *fid == id).unwrap();
+        let file = files[file_pos];
+
+        let maybe_empty_pos = files
+            .iter()
+            .position(|&(fid, _pos, len)| fid == -1 && len >= file.2);
+        if let Some(empty_pos) = maybe_empty_pos {
+            let (_, epos, elen) = files[empty_pos];
+            if epos < file.1 {
+                files[file_pos].0 = -1;
+
+                if file.2 < elen {
+                    files.insert(empty_pos + 1, (-1, epos + file.2, elen - file.2));
+                }
+
+                files[empty_pos] = (id, epos, file.2);
+            }
+        }
+
+        id -= 1;
+    }
+
+    let mut checksum: usize = 0;
+    for file in files {
+        if file.0 != -1 {
+            for i in 0..file.2 {
+                let block_id = file.1 + i;
+
+                checksum += (file.0 as usize) * block_id;
+            }
+        }
+    }
+
+    checksum
+}
+
+impl Solution for Day09 {
+    type Parsed = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(blocks: &Self::Parsed) -> Answer {
+        solve_p1(blocks).into()
+    }
+
+    fn part2(blocks: &Self::Parsed) -> Answer {
+        solve_p2(blocks).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"2333133121414131402"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 1928);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 2858);
+    }
+}
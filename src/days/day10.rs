@@ -0,0 +1,141 @@
+use crate::{
+    error::ParseError,
+    map::{parse_map, Grid},
+    vec2::Vec2i,
+    Answer, Solution,
+};
+
+pub struct Day10;
+
+type Heights = Grid<u8>;
+
+fn parse(input: &str) -> Result<Heights, ParseError> {
+    for (y, line) in input.lines().enumerate() {
+        if let Some(x) = line.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseError::new(y, x, line, "expected a height digit"));
+        }
+    }
+    Ok(parse_map(input)?.map_cells(|c| c.to_digit(10).unwrap() as u8))
+}
+
+fn solve_p1(heights: &Heights) -> usize {
+    let start_positions = start_positions(heights);
+
+    let mut trails = 0;
+    for start_pos in start_positions {
+        trails += walk_from(heights, start_pos);
+    }
+
+    trails
+}
+
+fn walk_from(heights: &Heights, start_pos: Vec2i) -> usize {
+    let mut visited = Grid::new(heights.w, heights.h, false);
+
+    let mut stack = Vec::new();
+    let mut count = 0;
+
+    stack.push((start_pos, 0));
+
+    while let Some((p, h)) = stack.pop() {
+        if heights.read(p) == Some(h) && !visited[&p] {
+            visited[&p] = true;
+            if h == 9 {
+                count += 1;
+            } else {
+                stack.extend(heights.neighbours_matching(p, |nh| nh == h + 1));
+            }
+        }
+    }
+
+    count
+}
+
+fn start_positions(heights: &Heights) -> Vec<Vec2i> {
+    heights.find_all(0).collect()
+}
+
+fn solve_p2(heights: &Heights) -> usize {
+    let start_positions = start_positions(heights);
+
+    let mut trails = 0;
+    for start_pos in start_positions {
+        trails += walk_from2(heights, start_pos);
+    }
+
+    trails
+}
+
+fn walk_from2(heights: &Heights, start_pos: Vec2i) -> usize {
+    let mut stack = Vec::new();
+    let mut count = 0;
+
+    stack.push((start_pos, 0));
+
+    while let Some((p, h)) = stack.pop() {
+        if heights.read(p) == Some(h) {
+            if h == 9 {
+                count += 1;
+            } else {
+                stack.extend(heights.neighbours_matching(p, |nh| nh == h + 1));
+            }
+        }
+    }
+
+    count
+}
+
+impl Solution for Day10 {
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(heights: &Self::Parsed) -> Answer {
+        solve_p1(heights).into()
+    }
+
+    fn part2(heights: &Self::Parsed) -> Answer {
+        solve_p2(heights).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_P1EX1: &str = r#"0123
+1234
+8765
+9876"#;
+
+    const INPUT_P1EX5: &str = r#"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732"#;
+
+    const INPUT_P2EX1: &str = r#"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_P1EX1).unwrap()), 1);
+        assert_eq!(solve_p1(&parse(INPUT_P1EX5).unwrap()), 36);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_P2EX1).unwrap()), 81);
+    }
+}
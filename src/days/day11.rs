@@ -0,0 +1,93 @@
+use crate::{error::ParseError, memo::Memo, parse::ints, Answer, Solution};
+
+pub struct Day11;
+
+fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    ints(input)
+}
+
+fn solve_p1(stones: &[u64], count: usize) -> u64 {
+    let mut ints = stones.to_vec();
+
+    for _ in 0..count {
+        let n = ints.len();
+        for i in 0..n {
+            let j = n - 1 - i;
+
+            let x = ints[j];
+
+            if x == 0 {
+                ints[j] = 1;
+            } else if x.to_string().len().is_multiple_of(2) {
+                let xs = x.to_string();
+                let (x1s, x2s) = xs.split_at(xs.len() / 2);
+                ints[j] = x2s.parse::<u64>().unwrap();
+                ints.insert(j, x1s.parse::<u64>().unwrap());
+            } else {
+                ints[j] = x * 2024;
+            }
+        }
+    }
+
+    ints.len() as u64
+}
+
+/// Number of stones that `x` turns into after `count` blinks.
+fn run(memo: &mut Memo<(u64, usize), usize>, x: u64, count: usize) -> usize {
+    if count == 0 {
+        return 1;
+    }
+
+    memo.get_or_compute(&(x, count), |memo| {
+        if x == 0 {
+            run(memo, 1, count - 1)
+        } else if x.to_string().len().is_multiple_of(2) {
+            let xs = x.to_string();
+            let (x1s, x2s) = xs.split_at(xs.len() / 2);
+            run(memo, x1s.parse::<u64>().unwrap(), count - 1)
+                + run(memo, x2s.parse::<u64>().unwrap(), count - 1)
+        } else {
+            run(memo, x * 2024, count - 1)
+        }
+    })
+}
+
+fn solve_p2(stones: &[u64], count: usize) -> usize {
+    let mut memo = Memo::new();
+    let sum = stones.iter().map(|&x| run(&mut memo, x, count)).sum();
+    log::debug!("Stone cache: {}", memo.stats());
+    sum
+}
+
+impl Solution for Day11 {
+    type Parsed = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(stones: &Self::Parsed) -> Answer {
+        solve_p1(stones, 25).into()
+    }
+
+    fn part2(stones: &Self::Parsed) -> Answer {
+        solve_p2(stones, 75).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"125 17"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap(), 25), 55312);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap(), 25), 55312);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    error::ParseError,
+    map::{parse_map, Map},
+    regions::{Connectivity, Regions},
+    Answer, Solution,
+};
+
+pub struct Day12;
+
+fn parse(input: &str) -> Result<Map, ParseError> {
+    parse_map(input)
+}
+
+fn solve_p1(map: &Map) -> usize {
+    Regions::label(map, Connectivity::Four)
+        .iter()
+        .map(|region| region.area * region.perimeter)
+        .sum()
+}
+
+fn solve_p2(map: &Map) -> usize {
+    Regions::label(map, Connectivity::Four)
+        .iter()
+        .map(|region| region.area * region.sides)
+        .sum()
+}
+
+impl Solution for Day12 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Answer {
+        solve_p1(map).into()
+    }
+
+    fn part2(map: &Self::Parsed) -> Answer {
+        solve_p2(map).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"AAAA
+BBCD
+BBCC
+EEEC"#;
+
+    const INPUT_EX2: &str = r#"OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO"#;
+
+    const INPUT_EX3: &str = r#"RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE"#;
+
+    const INPUT_EX4: &str = r#"EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE"#;
+
+    const INPUT_EX5: &str = r#"AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 140);
+        assert_eq!(solve_p1(&parse(INPUT_EX2).unwrap()), 772);
+        assert_eq!(solve_p1(&parse(INPUT_EX3).unwrap()), 1930);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 80);
+        assert_eq!(solve_p2(&parse(INPUT_EX2).unwrap()), 436);
+        assert_eq!(solve_p2(&parse(INPUT_EX3).unwrap()), 1206);
+        assert_eq!(solve_p2(&parse(INPUT_EX4).unwrap()), 236);
+        assert_eq!(solve_p2(&parse(INPUT_EX5).unwrap()), 368);
+    }
+}
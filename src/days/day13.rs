@@ -0,0 +1,158 @@
+use crate::{
+    error::ParseError, linear::min_cost_solution_within, parse::sections, vec2::Vec2l, Answer,
+    Solution,
+};
+pub struct Day13;
+
+pub struct ClawMachine {
+    button_a: Vec2l,
+    button_b: Vec2l,
+    prize: Vec2l,
+}
+
+fn parse(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let mut machines = Vec::new();
+    for section in sections(input) {
+        let [a, b, prize] = &section[..] else {
+            return Err(section[0].error("expected two buttons and a prize per claw machine"));
+        };
+        let [ax, ay] = a.ints_exact()?;
+        let [bx, by] = b.ints_exact()?;
+        let [px, py] = prize.ints_exact()?;
+        machines.push(ClawMachine {
+            button_a: Vec2l::new(ax, ay),
+            button_b: Vec2l::new(bx, by),
+            prize: Vec2l::new(px, py),
+        });
+    }
+    Ok(machines)
+}
+
+impl ClawMachine {
+    /// Button moves with their token costs.
+    fn buttons(&self) -> [(Vec2l, i64); 2] {
+        [(self.button_a, 3), (self.button_b, 1)]
+    }
+}
+
+/// Cheapest presses of `buttons`, given as (move, token cost), that land
+/// exactly on `prize` with at most `max_presses` of each, with their total
+/// cost.
+fn min_tokens(
+    buttons: &[(Vec2l, i64)],
+    prize: Vec2l,
+    max_presses: Option<i64>,
+) -> Option<(Vec<i64>, i64)> {
+    let rows = vec![
+        buttons.iter().map(|(step, _)| step.x).collect(),
+        buttons.iter().map(|(step, _)| step.y).collect(),
+    ];
+    let costs: Vec<i64> = buttons.iter().map(|&(_, cost)| cost).collect();
+    let max = vec![max_presses; buttons.len()];
+    // Two rows leave a single free variable unless the buttons are all
+    // collinear, and then part 1 bounds the presses.
+    min_cost_solution_within(&rows, &[prize.x, prize.y], &costs, &max)
+        .expect("at most one unbounded free variable")
+}
+
+// Part 1
+
+fn solve_p1(machines: &[ClawMachine]) -> i64 {
+    machines
+        .iter()
+        .filter_map(|machine| min_tokens(&machine.buttons(), machine.prize, Some(100)))
+        .map(|(_, cost)| cost)
+        .sum()
+}
+
+// Part 2
+
+fn solve_p2(machines: &[ClawMachine], adds: i64) -> i64 {
+    machines
+        .iter()
+        .filter_map(|machine| {
+            let prize = machine.prize + Vec2l::new(adds, adds);
+            min_tokens(&machine.buttons(), prize, None)
+        })
+        .map(|(_, cost)| cost)
+        .sum()
+}
+
+impl Solution for Day13 {
+    type Parsed = Vec<ClawMachine>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(machines: &Self::Parsed) -> Answer {
+        solve_p1(machines).into()
+    }
+
+    fn part2(machines: &Self::Parsed) -> Answer {
+        solve_p2(machines, 10000000000000i64).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 480);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap(), 0), 480);
+    }
+
+    #[test]
+    fn degenerate_test() {
+        // Collinear buttons, where A moves four times as far for 3 tokens.
+        let buttons = [(Vec2l::new(4, 4), 3), (Vec2l::new(1, 1), 1)];
+        assert_eq!(
+            min_tokens(&buttons, Vec2l::new(10, 10), None),
+            Some((vec![2, 2], 8))
+        );
+        assert_eq!(min_tokens(&buttons, Vec2l::new(10, 9), None), None);
+
+        // B alone is cheapest, but needs 150 presses. Within 100 presses
+        // A has to make up the rest.
+        let buttons = [(Vec2l::new(2, 2), 3), (Vec2l::new(1, 1), 1)];
+        let prize = Vec2l::new(150, 150);
+        assert_eq!(min_tokens(&buttons, prize, None), Some((vec![0, 150], 150)));
+        assert_eq!(
+            min_tokens(&buttons, prize, Some(100)),
+            Some((vec![25, 100], 175))
+        );
+        assert_eq!(min_tokens(&buttons, Vec2l::new(400, 400), Some(100)), None);
+
+        let buttons = [
+            (Vec2l::new(3, 1), 3),
+            (Vec2l::new(1, 3), 1),
+            (Vec2l::new(1, 1), 2),
+        ];
+        assert_eq!(
+            min_tokens(&buttons, Vec2l::new(5, 5), None),
+            Some((vec![1, 1, 1], 6))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    error::ParseError, math::crt, parse::lines, sparse::SparseGrid, vec2::Vec2i, Answer, Solution,
+};
+
+pub struct Day14;
+
+fn solve_p1(robots: &[(Vec2i, Vec2i)], width: i32, height: i32, steps: i32) -> i64 {
+    let mut xs = robots.to_vec();
+
+    for _ in 0..steps {
+        step(&mut xs, width, height);
+    }
+
+    let (quadrant1_count, quadrant2_count, quadrant3_count, quadrant4_count) =
+        count_quadrants(&xs, width, height);
+
+    quadrant1_count * quadrant2_count * quadrant3_count * quadrant4_count
+}
+
+/// Robots as (position, velocity) pairs.
+fn parse(input: &str) -> Result<Vec<(Vec2i, Vec2i)>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let [px, py, vx, vy] = line.ints_exact()?;
+            Ok((Vec2i::new(px, py), Vec2i::new(vx, vy)))
+        })
+        .collect()
+}
+
+fn count_quadrants(xs: &[(Vec2i, Vec2i)], width: i32, height: i32) -> (i64, i64, i64, i64) {
+    let mut quadrant1_count = 0;
+    let mut quadrant2_count = 0;
+    let mut quadrant3_count = 0;
+    let mut quadrant4_count = 0;
+    for (p, _) in xs {
+        if p.x < width / 2 && p.y < height / 2 {
+            quadrant1_count += 1;
+        } else if p.x >= (1 + (width / 2)) && p.y < height / 2 {
+            quadrant2_count += 1;
+        } else if p.x < width / 2 && p.y >= (1 + (height / 2)) {
+            quadrant3_count += 1;
+        } else if p.x >= (1 + (width / 2)) && p.y >= (1 + (height / 2)) {
+            quadrant4_count += 1;
+        }
+    }
+    (
+        quadrant1_count,
+        quadrant2_count,
+        quadrant3_count,
+        quadrant4_count,
+    )
+}
+
+/// Number of robots on each occupied tile.
+fn robot_counts(xs: &[(Vec2i, Vec2i)]) -> SparseGrid<usize> {
+    let mut counts = SparseGrid::new();
+    for &(p, _) in xs {
+        match counts.get_mut(p) {
+            Some(n) => *n += 1,
+            None => {
+                counts.insert(p, 1);
+            }
+        }
+    }
+    counts
+}
+
+fn step(xs: &mut Vec<(Vec2i, Vec2i)>, width: i32, height: i32) {
+    for (p, v) in xs {
+        *p = (*p + *v) % Vec2i::new(width, height);
+    }
+}
+
+/// Spread of the robots along one axis after `steps` steps, as n² times
+/// the variance of their coordinates.
+fn spread(coords: impl Iterator<Item = (i32, i32)>, size: i32, steps: i32) -> i64 {
+    let (mut n, mut sum, mut sum_sq) = (0i64, 0i64, 0i64);
+    for (p, v) in coords {
+        let c = (p + v * steps).rem_euclid(size) as i64;
+        n += 1;
+        sum += c;
+        sum_sq += c * c;
+    }
+    n * sum_sq - sum * sum
+}
+
+/// Step, within one period of `size`, at which the robots bunch up the most
+/// along one axis.
+fn tightest_step(coords: impl Iterator<Item = (i32, i32)> + Clone, size: i32) -> i64 {
+    (0..size)
+        .min_by_key(|&steps| spread(coords.clone(), size, steps))
+        .unwrap() as i64
+}
+
+fn solve_p2(robots: &[(Vec2i, Vec2i)], width: i32, height: i32) -> i64 {
+    // x coordinates repeat every `width` steps and y every `height` steps,
+    // so the picture appears when both axes are at their tightest.
+    let x_step = tightest_step(robots.iter().map(|(p, v)| (p.x, v.x)), width);
+    let y_step = tightest_step(robots.iter().map(|(p, v)| (p.y, v.y)), height);
+    let (steps, _) =
+        crt(&[(x_step, width as i64), (y_step, height as i64)]).expect("the periods are coprime");
+
+    if log::log_enabled!(log::Level::Info) {
+        let mut xs = robots.to_vec();
+        for _ in 0..steps {
+            step(&mut xs, width, height);
+        }
+        log::info!("After {} steps:\n{}", steps, robot_counts(&xs).render('.'));
+    }
+    steps
+}
+
+impl Solution for Day14 {
+    type Parsed = Vec<(Vec2i, Vec2i)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(robots: &Self::Parsed) -> Answer {
+        solve_p1(robots, 101, 103, 100).into()
+    }
+
+    fn part2(robots: &Self::Parsed) -> Answer {
+        solve_p2(robots, 101, 103).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap(), 11, 7, 100), 12);
+    }
+
+    #[test]
+    fn p2_test1() {
+        // Robots that all land in a 3x3 block after 30 steps.
+        let mut seed = 7i32;
+        let mut random = |n: i32| {
+            seed = (seed * 1103 + 12345).rem_euclid(1 << 16);
+            seed % n
+        };
+        let robots: Vec<_> = (0..20)
+            .map(|i| {
+                let v = Vec2i::new(random(9) - 4, random(9) - 4);
+                let target = Vec2i::new(4 + i % 3, 3 + i / 3 % 3);
+                ((target - v * 30) % Vec2i::new(11, 7), v)
+            })
+            .collect();
+        assert_eq!(solve_p2(&robots, 11, 7), 30);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    dir::Dir4, error::ParseError, map::Map, parse::grid_and_rest, sparse::SparseGrid, vec2::Vec2i,
+    Answer, Solution,
+};
+use std::collections::HashSet;
+
+pub struct Day15;
+
+pub struct Warehouse {
+    map: Map,
+    movements: Vec<Dir4>,
+}
+
+fn parse(input: &str) -> Result<Warehouse, ParseError> {
+    let (map, rest) = grid_and_rest(input)?;
+
+    if let Some((p, _)) = map.iter().find(|(_, c)| !"#.O@".contains(**c)) {
+        return Err(map.error_at(p, "expected one of # . O @"));
+    }
+    map.find_unique('@')?;
+
+    let mut movements = Vec::new();
+    for line in rest {
+        for (x, c) in line.text.char_indices().filter(|(_, c)| !c.is_whitespace()) {
+            let dir = Dir4::from_arrow_char(c)
+                .ok_or_else(|| line.error_at(&line.text[x..], "expected one of ^ > v <"))?;
+            movements.push(dir);
+        }
+    }
+
+    Ok(Warehouse { map, movements })
+}
+
+// Part 1
+
+fn objects(map: &Map) -> (Vec2i, SparseGrid<char>) {
+    let start_pos = map.find('@').expect("parse checks for exactly one robot");
+    let objects = SparseGrid::from_grid(map, |&c| c != '.' && c != '@');
+
+    (start_pos, objects)
+}
+
+fn solve_p1(warehouse: &Warehouse) -> i32 {
+    simulate(objects(&warehouse.map), &warehouse.movements)
+}
+
+/// Moves the robot through the warehouse and sums the GPS coordinates of
+/// the boxes, counting wide boxes by their left half.
+fn simulate((mut pos, mut map): (Vec2i, SparseGrid<char>), movements: &[Dir4]) -> i32 {
+    for &movement in movements {
+        let dir = movement.to_vec2i();
+
+        if try_move(pos, dir, &mut map) {
+            pos += dir;
+        }
+    }
+
+    map.into_iter()
+        .filter_map(|(p, c)| if c == 'O' || c == '[' { Some(p) } else { None })
+        .map(|Vec2i { x, y }| y * 100 + x)
+        .sum()
+}
+
+/// Moves the robot at `pos` one step in `dir`, pushing every box in the
+/// way. Returns false, changing nothing, when that would push into a wall.
+fn try_move(pos: Vec2i, dir: Vec2i, map: &mut SparseGrid<char>) -> bool {
+    // Everything that moves, in order of distance from the robot.
+    let mut moving = vec![pos];
+    let mut seen = HashSet::from([pos]);
+    let mut i = 0;
+    while i < moving.len() {
+        let new_pos = moving[i] + dir;
+        i += 1;
+        let other_half = match map.get(new_pos) {
+            None => continue,
+            Some('#') => return false,
+            Some('O') => None,
+            // Pushed sideways, the other half is next in line anyway.
+            Some('[') if dir.y != 0 => Some(new_pos + Vec2i::new(1, 0)),
+            Some(']') if dir.y != 0 => Some(new_pos + Vec2i::new(-1, 0)),
+            Some('[' | ']') => None,
+            Some(c) => panic!("unexpected '{}' in the warehouse", c),
+        };
+        for p in [Some(new_pos), other_half].into_iter().flatten() {
+            if seen.insert(p) {
+                moving.push(p);
+            }
+        }
+    }
+
+    // Farthest first, so every cell moves into a free space. The robot
+    // itself is not stored in the map.
+    for &p in moving.iter().rev() {
+        map.move_cell(p, p + dir);
+    }
+    true
+}
+
+// Part 2
+
+fn wide_objects(map: &Map) -> (Vec2i, SparseGrid<char>) {
+    objects(&map.scale_x(2, |c| match c {
+        '#' => "##",
+        'O' => "[]",
+        '@' => "@.",
+        _ => "..",
+    }))
+}
+
+fn solve_p2(warehouse: &Warehouse) -> i32 {
+    simulate(wide_objects(&warehouse.map), &warehouse.movements)
+}
+
+impl Solution for Day15 {
+    type Parsed = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(warehouse: &Self::Parsed) -> Answer {
+        solve_p1(warehouse).into()
+    }
+
+    fn part2(warehouse: &Self::Parsed) -> Answer {
+        solve_p2(warehouse).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX0: &str = r#"########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<"#;
+
+    const INPUT_EX1: &str = r#"##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX0).unwrap()), 2028);
+    }
+    #[test]
+    fn parse_test() {
+        let crlf = INPUT_EX0.replace('\n', "\r\n");
+        assert_eq!(solve_p1(&parse(&crlf).unwrap()), 2028);
+
+        let Err(e) = parse(&INPUT_EX0.replace("<^^>", "<^x>")) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((e.line, e.column), (10, 3));
+    }
+
+    #[test]
+    fn p1_test2() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 10092);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 9021);
+    }
+}
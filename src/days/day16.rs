@@ -0,0 +1,138 @@
+use crate::{
+    dir::Dir4,
+    error::ParseError,
+    map::{parse_map, Map},
+    render::{colour_enabled_for, Colour, Renderer},
+    search::{all_shortest_paths, dijkstra, SearchProblem},
+    vec2::Vec2i,
+    Answer, Solution,
+};
+use std::{collections::HashSet, io};
+
+pub struct Day16;
+
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    let mut map = parse_map(input)?;
+    let start_pos = map.take_marker('S', '.')?;
+    let end_pos = map.take_marker('E', '.')?;
+    Ok(Maze {
+        map,
+        start_pos,
+        end_pos,
+    })
+}
+
+pub struct Maze {
+    pub map: Map,
+    pub start_pos: Vec2i,
+    pub end_pos: Vec2i,
+}
+
+impl SearchProblem for Maze {
+    type State = (Vec2i, Dir4);
+
+    fn start(&self) -> Self::State {
+        (self.start_pos, Dir4::Right)
+    }
+
+    fn neighbours(
+        &self,
+        (pos, dir): &Self::State,
+    ) -> impl IntoIterator<Item = (Self::State, usize)> {
+        let mut moves = vec![
+            ((*pos, dir.turn_left()), 1000),
+            ((*pos, dir.turn_right()), 1000),
+        ];
+        let new_pos = pos.step(*dir, 1);
+        if self.map.read(new_pos) == Some('.') {
+            moves.push(((new_pos, *dir), 1));
+        }
+        moves
+    }
+
+    fn is_goal(&self, (pos, _): &Self::State) -> bool {
+        *pos == self.end_pos
+    }
+}
+
+fn solve_p1(maze: &Maze) -> Option<usize> {
+    let best = dijkstra(maze)?;
+    if log::log_enabled!(log::Level::Debug) {
+        let mut path: Vec<Vec2i> = best.states.iter().map(|&(pos, _)| pos).collect();
+        path.dedup();
+        let view = Renderer::new(&maze.map)
+            .colour(colour_enabled_for(&io::stderr()))
+            .palette('#', Colour::Blue)
+            .path(&path, Colour::Yellow);
+        log::debug!("Best path:\n{}", view);
+    }
+    Some(best.cost)
+}
+
+fn solve_p2(maze: &Maze) -> Option<usize> {
+    let best_paths = all_shortest_paths(maze)?;
+    let best_paths_positions: HashSet<Vec2i> =
+        best_paths.states.into_iter().map(|(pos, _)| pos).collect();
+    log::debug!(
+        "Tiles on a best path:\n{}",
+        Renderer::new(&maze.map)
+            .colour(colour_enabled_for(&io::stderr()))
+            .palette('#', Colour::Blue)
+            .highlight(best_paths_positions.iter().copied(), Colour::Yellow)
+    );
+
+    Some(best_paths_positions.len())
+}
+
+impl Solution for Day16 {
+    type Parsed = Maze;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(maze: &Self::Parsed) -> Answer {
+        solve_p1(maze).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(maze: &Self::Parsed) -> Answer {
+        solve_p2(maze).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), Some(7036));
+    }
+
+    #[test]
+    fn p1_walled_in_test() {
+        let maze = parse("#####\n#S#E#\n#####").unwrap();
+        assert_eq!(Day16::part1(&maze), Answer::Unsolved);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), Some(45));
+    }
+}
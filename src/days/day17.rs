@@ -0,0 +1,178 @@
+use crate::{error::ParseError, parse::sections_exact, Answer, Solution};
+
+pub struct Day17;
+
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u64>,
+}
+
+fn parse(input: &str) -> Result<Computer, ParseError> {
+    let [registers, program] = sections_exact(input)?;
+    let [a, b, c] = &registers[..] else {
+        return Err(registers[0].error("expected registers A, B and C"));
+    };
+    let [a] = a.ints_exact()?;
+    let [b] = b.ints_exact()?;
+    let [c] = c.ints_exact()?;
+
+    let line = &program[0];
+    let program = line.ints()?;
+    if program.is_empty() || !program.len().is_multiple_of(2) || program.iter().any(|&x| x > 7) {
+        return Err(line.error("expected a program of 3-bit opcode and operand pairs"));
+    }
+    Ok(Computer { a, b, c, program })
+}
+
+fn solve_p1(computer: &Computer) -> String {
+    let output = run_program(computer.a, computer.b, computer.c, &computer.program, false);
+
+    output
+        .into_iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn run_program(
+    mut a: u64,
+    mut b: u64,
+    mut c: u64,
+    isl: &[u64],
+    one_output: bool, // for part 2
+) -> Vec<u64> {
+    let mut i: u64 = 0;
+    let mut output = Vec::new();
+    loop {
+        let opcode = isl[i as usize];
+        let operand = isl[(i + 1) as usize];
+
+        i += 2;
+
+        let val = match operand {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => unreachable!(),
+        };
+
+        match opcode {
+            0 => a /= 1 << val,
+            1 => b ^= operand,
+            2 => {
+                b = val % 8;
+            }
+            3 => {
+                if a != 0 {
+                    i = operand
+                }
+            }
+            4 => {
+                b ^= c;
+            }
+            5 => {
+                output.push(val % 8);
+                if one_output {
+                    return output;
+                }
+            }
+            6 => b = a / (1 << val),
+            7 => c = a / (1 << val),
+            _ => unreachable!(),
+        }
+
+        if i >= (isl.len() as u64) {
+            break;
+        }
+    }
+    output
+}
+
+/*
+Program: 2,4,1,2,7,5,4,1,1,3,5,5,0,3,3,0
+
+ 2,4    b = a % 8
+ 1,2    b = b ^ 2
+ 7,5    c = a / (1<<b)
+ 4,1    b = b ^ c
+ 1,3    b = b ^ 3
+ 5,5    output b % 8
+ 0,3    a = a / (1 << 3)
+ 3,0    if a != 0 goto start
+ */
+
+fn solve_p2(computer: &Computer) -> u64 {
+    let isl = &computer.program;
+
+    let mut possible_start_as = vec![0];
+
+    for &expected_output in isl.iter().rev() {
+        let mut next_possible_start_a = Vec::new();
+        for a in possible_start_as {
+            for i in 0..=7 {
+                let next_a = (a << 3) | i;
+                let output = run_program(next_a, 0, 0, isl, true)[0];
+                if output == expected_output {
+                    next_possible_start_a.push(next_a);
+                }
+            }
+        }
+        possible_start_as = next_possible_start_a;
+    }
+
+    possible_start_as.into_iter().min().unwrap()
+}
+
+impl Solution for Day17 {
+    type Parsed = Computer;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(computer: &Self::Parsed) -> Answer {
+        solve_p1(computer).into()
+    }
+
+    fn part2(computer: &Self::Parsed) -> Answer {
+        solve_p2(computer).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0"#;
+
+    /*
+    0 3  a = a / 8
+    5 4  output a % 8
+    3 0  if a!= 0 goto start
+    */
+    const INPUT_EX2: &str = r#"Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX2).unwrap()), 117440);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    disjoint_set::DisjointSet, error::ParseError, map::Grid, parse::lines, vec2::Vec2i, Answer,
+    Solution,
+};
+pub struct Day18;
+
+fn parse(input: &str) -> Result<Vec<Vec2i>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let [x, y] = line.ints_exact()?;
+            Ok(Vec2i::new(x, y))
+        })
+        .collect()
+}
+
+fn corrupted_grid(bytes: &[Vec2i], w: i32) -> Grid<bool> {
+    let mut grid = Grid::new(w, w, false);
+    for p in bytes {
+        grid.set(p, true);
+    }
+    grid
+}
+
+fn solve_p1(xs: &[Vec2i], w: i32, n: usize) -> usize {
+    let obstacles = corrupted_grid(&xs[0..n], w);
+
+    shortest_path(&obstacles).unwrap()
+}
+
+fn shortest_path(obstacles: &Grid<bool>) -> Option<usize> {
+    let target = Vec2i::new(obstacles.w - 1, obstacles.h - 1);
+    let dist = obstacles.distance_field([Vec2i::new(0, 0)], |blocked| !blocked);
+    dist[target].map(|d| d as usize)
+}
+
+fn solve_p2(xs: &[Vec2i], w: i32, i0: usize) -> String {
+    // Start with every byte fallen and remove them again from the last one.
+    // The first byte whose removal reconnects the corners is the one that
+    // blocked the exit. Removing bytes only ever joins regions, so the set
+    // never has to undo a union and needs no rollback.
+    let mut fallen: Grid<usize> = Grid::new(w, w, 0);
+    for p in xs {
+        if let Some(n) = fallen.get_mut(p) {
+            *n += 1;
+        }
+    }
+
+    let index = |p: Vec2i| (p.y * w + p.x) as usize;
+    let mut regions = DisjointSet::new((w * w) as usize);
+    let free_up = |regions: &mut DisjointSet, fallen: &Grid<usize>, p: Vec2i| {
+        for (n, count) in fallen.neighbours_4_in_bounds(p) {
+            if count == 0 {
+                regions.union(index(p), index(n));
+            }
+        }
+    };
+    for p in fallen.find_all(0) {
+        free_up(&mut regions, &fallen, p);
+    }
+
+    let (start, exit) = (index(Vec2i::new(0, 0)), index(Vec2i::new(w - 1, w - 1)));
+    assert!(
+        !regions.same(start, exit),
+        "No solution for the second part found"
+    );
+    for &p in xs[i0..].iter().rev() {
+        // Bytes outside the grid were never counted.
+        let Some(count) = fallen.get_mut(p) else {
+            continue;
+        };
+        *count -= 1;
+        if *count == 0 {
+            free_up(&mut regions, &fallen, p);
+            if regions.same(start, exit) {
+                return format!("{},{}", p.x, p.y);
+            }
+        }
+    }
+    panic!("No solution for the second part found");
+}
+
+impl Solution for Day18 {
+    type Parsed = Vec<Vec2i>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(bytes: &Self::Parsed) -> Answer {
+        solve_p1(bytes, 71, 1024).into()
+    }
+
+    fn part2(bytes: &Self::Parsed) -> Answer {
+        solve_p2(bytes, 71, 1024).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap(), 7, 12), 22);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap(), 7, 12), "6,1");
+
+        let outside = format!("{}\n9,9", INPUT_EX1);
+        assert_eq!(solve_p2(&parse(&outside).unwrap(), 7, 12), "6,1");
+    }
+}
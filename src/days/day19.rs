@@ -0,0 +1,132 @@
+use crate::{error::ParseError, memo::Memo, parse::sections_exact, Answer, Solution};
+
+pub struct Day19;
+
+pub struct Onsen {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl Onsen {
+    fn patterns(&self) -> Vec<&str> {
+        self.patterns.iter().map(String::as_str).collect()
+    }
+}
+
+fn parse(input: &str) -> Result<Onsen, ParseError> {
+    let [pattern_lines, design_lines] = sections_exact(input)?;
+    let [line] = &pattern_lines[..] else {
+        return Err(pattern_lines[1].error("expected towel patterns on a single line"));
+    };
+    if let Some(c) = line.text.find(|c: char| !"wubrg, ".contains(c)) {
+        return Err(line.error_at(&line.text[c..], "expected a towel color"));
+    }
+    let patterns = line.text.split(',').map(|s| s.trim().to_string()).collect();
+    let designs = design_lines
+        .iter()
+        .map(|line| line.text.trim().to_string())
+        .collect();
+    Ok(Onsen { patterns, designs })
+}
+
+fn solve_p1(onsen: &Onsen) -> usize {
+    let patterns = onsen.patterns();
+    let designs = &onsen.designs;
+
+    let mut memo = Memo::new();
+    designs
+        .iter()
+        .filter(|design| match_pattern(&mut memo, design, &patterns))
+        .count()
+}
+
+fn match_pattern<'a>(
+    memo: &mut Memo<&'a str, bool>,
+    design: &'a str,
+    patterns: &[&'a str],
+) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    memo.get_or_compute(&design, |memo| {
+        patterns.iter().any(|pattern| {
+            design
+                .strip_prefix(pattern)
+                .is_some_and(|rest| match_pattern(memo, rest, patterns))
+        })
+    })
+}
+
+fn solve_p2(onsen: &Onsen) -> usize {
+    let patterns = onsen.patterns();
+    let designs = &onsen.designs;
+
+    let mut memo = Memo::new();
+    let count = designs
+        .iter()
+        .map(|design| match_pattern2(&mut memo, design, &patterns))
+        .sum();
+    log::debug!("Design cache: {}", memo.stats());
+    count
+}
+
+fn match_pattern2<'a>(
+    memo: &mut Memo<&'a str, usize>,
+    design: &'a str,
+    patterns: &[&'a str],
+) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+
+    memo.get_or_compute(&design, |memo| {
+        patterns
+            .iter()
+            .filter_map(|pattern| design.strip_prefix(pattern))
+            .map(|rest| match_pattern2(memo, rest, patterns))
+            .sum()
+    })
+}
+
+impl Solution for Day19 {
+    type Parsed = Onsen;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(onsen: &Self::Parsed) -> Answer {
+        solve_p1(onsen).into()
+    }
+
+    fn part2(onsen: &Self::Parsed) -> Answer {
+        solve_p2(onsen).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 6);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), 16);
+    }
+}
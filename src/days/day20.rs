@@ -0,0 +1,130 @@
+use crate::{
+    error::ParseError,
+    map::{parse_map, Grid, Map},
+    vec2::Vec2i,
+    Answer, Solution,
+};
+use std::collections::HashMap;
+
+pub struct Day20;
+
+fn parse(input: &str) -> Result<(Map, Vec2i), ParseError> {
+    let map = parse_map(input)?;
+    let start_pos = map.find_unique('S')?;
+    map.find_unique('E')?;
+    Ok((map, start_pos))
+}
+
+fn possible_cheats_from(
+    start_pos: Vec2i,
+    from_start: u32,
+    to_end: &Grid<Option<u32>>,
+    best: u32,
+    max_cheat_length: i32,
+    savings: &mut HashMap<u32, usize>,
+) {
+    for y in -max_cheat_length..=max_cheat_length {
+        for x in -max_cheat_length..=max_cheat_length {
+            let end_pos = start_pos + Vec2i::new(x, y);
+            let d = start_pos.manhattan(end_pos);
+            if d < 2 || d > max_cheat_length {
+                continue;
+            }
+
+            if let Some(Some(rest)) = to_end.read(end_pos) {
+                let length = from_start + d as u32 + rest;
+                if length < best {
+                    *savings.entry(best - length).or_default() += 1;
+                }
+            }
+        }
+    }
+}
+
+fn solve(
+    (map, start_pos): &(Map, Vec2i),
+    max_cheat_length: i32,
+    min_saving: u32,
+    print_limit: u32,
+) -> usize {
+    let on_track = |c| c != '#';
+    let end_pos = map.find('E').expect("parse checks for the end");
+    let from_start = map.distance_field([*start_pos], on_track);
+    let to_end = map.distance_field([end_pos], on_track);
+    let best = from_start[end_pos].expect("the end is reachable");
+    log::info!("Best length: {}", best);
+
+    let mut savings: HashMap<u32, usize> = HashMap::new();
+
+    for (p, &d) in from_start.iter() {
+        if let Some(d) = d {
+            possible_cheats_from(p, d, &to_end, best, max_cheat_length, &mut savings);
+        }
+    }
+
+    let mut savings: Vec<_> = savings.into_iter().collect();
+
+    savings.sort_unstable_by_key(|&(k, _)| k);
+
+    let mut total_count = 0;
+    for (saving, count) in savings {
+        if saving >= print_limit {
+            log::info!(
+                "There is {} cheats that saves {} picoseconds",
+                count,
+                saving
+            );
+        }
+        if saving >= min_saving {
+            total_count += count;
+        }
+    }
+    total_count
+}
+
+impl Solution for Day20 {
+    type Parsed = (Map, Vec2i);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(track: &Self::Parsed) -> Answer {
+        solve(track, 2, 100, 10000).into()
+    }
+
+    fn part2(track: &Self::Parsed) -> Answer {
+        solve(track, 20, 100, 10000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve(&parse(INPUT_EX1).unwrap(), 2, 38, 0), 3);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve(&parse(INPUT_EX1).unwrap(), 20, 72, 50), 29);
+    }
+}
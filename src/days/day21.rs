@@ -0,0 +1,219 @@
+use crate::{
+    dir::Dir4, error::ParseError, memo::Memo, parse::lines, vec2::Vec2i, Answer, Solution,
+};
+use itertools::Itertools;
+use maplit::hashmap;
+use std::collections::{HashMap, VecDeque};
+
+pub struct Day21;
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let code = line.text.trim();
+            let chars: Vec<char> = code.chars().collect();
+            let valid = chars.len() == 4
+                && chars[..3].iter().all(|c| c.is_ascii_digit())
+                && chars[3] == 'A';
+            if valid {
+                Ok(code.to_string())
+            } else {
+                Err(line.error("expected a door code like 029A"))
+            }
+        })
+        .collect()
+}
+
+fn get_numpad() -> HashMap<char, Vec2i> {
+    let num_pad: HashMap<char, Vec2i> = hashmap! {
+        '_' => Vec2i::new(0, 3),
+        '0' => Vec2i::new(1, 3),
+        'A' => Vec2i::new(2, 3),
+        '1' => Vec2i::new(0, 2),
+        '2' => Vec2i::new(1, 2),
+        '3' => Vec2i::new(2, 2),
+        '4' => Vec2i::new(0, 1),
+        '5' => Vec2i::new(1, 1),
+        '6' => Vec2i::new(2, 1),
+        '7' => Vec2i::new(0, 0),
+        '8' => Vec2i::new(1, 0),
+        '9' => Vec2i::new(2, 0),
+    };
+    num_pad
+}
+
+fn get_dirpad() -> HashMap<char, Vec2i> {
+    let pad: HashMap<char, Vec2i> = hashmap! {
+        '_' => Vec2i::new(0, 0),
+        '^' => Vec2i::new(1, 0),
+        'A' => Vec2i::new(2, 0),
+        '<' => Vec2i::new(0, 1),
+        'v' => Vec2i::new(1, 1),
+        '>' => Vec2i::new(2, 1),
+    };
+    pad
+}
+
+type Pad = HashMap<char, Vec2i>;
+type Moves = VecDeque<char>;
+
+fn get_directions_to_target(target_start: &Vec2i, target_end: &Vec2i, pad: &Pad) -> Vec<Moves> {
+    if target_start == target_end {
+        let mut moves = Moves::new();
+        moves.push_back('A');
+        return vec![moves];
+    }
+
+    let invalid_pos = &pad[&'_'];
+
+    let dir = *target_end - *target_start;
+
+    let mut dirs = VecDeque::new();
+    if dir.x > 0 {
+        for _ in 0..dir.x.abs() {
+            dirs.push_back('>')
+        }
+    }
+    if dir.x < 0 {
+        for _ in 0..dir.x.abs() {
+            dirs.push_back('<')
+        }
+    }
+    if dir.y > 0 {
+        for _ in 0..dir.y.abs() {
+            dirs.push_back('v')
+        }
+    }
+    if dir.y < 0 {
+        for _ in 0..dir.y.abs() {
+            dirs.push_back('^')
+        }
+    }
+
+    let n = dirs.len();
+    let valid_moves: Vec<VecDeque<_>> = dirs
+        .into_iter()
+        .permutations(n)
+        .unique()
+        .filter_map(|moves| {
+            let mut next = *target_start;
+            for &move_char in &moves {
+                next = next.step(Dir4::from_arrow_char(move_char).unwrap(), 1);
+                if next == *invalid_pos || !pad.values().contains(&next) {
+                    return None;
+                }
+            }
+            let mut current: Moves = moves.into_iter().collect();
+            current.push_back('A');
+            Some(current)
+        })
+        .collect();
+    valid_moves
+}
+
+/// Cache key: the pad key the robot moves from and to, the number of
+/// directional pads still in between, and the index of the robot's pad.
+type PressCache = Memo<(char, char, usize, usize), usize>;
+
+fn solve(xs: &[String], num_robots: usize) -> usize {
+    let num_pad = get_numpad();
+    let dir_pad = get_dirpad();
+
+    let pads = vec![num_pad, dir_pad];
+
+    let mut memo = PressCache::new();
+
+    let mut sum = 0;
+    for code in xs {
+        let min_len = sequence_len(&mut memo, &pads, code.chars(), num_robots, 0);
+
+        let part_code = code[0..=2].parse::<usize>().unwrap();
+        let prod = min_len * part_code;
+        sum += prod;
+    }
+    log::debug!("Press cache: {}", memo.stats());
+    sum
+}
+
+/// Fewest presses on the outermost pad to type `keys` on `pads[pad]`,
+/// starting from 'A', with `num_robots` directional pads in between.
+fn sequence_len(
+    memo: &mut PressCache,
+    pads: &[Pad],
+    keys: impl IntoIterator<Item = char>,
+    num_robots: usize,
+    pad: usize,
+) -> usize {
+    let mut from = 'A';
+    let mut total_length = 0;
+    for to in keys {
+        total_length += press_len(memo, pads, from, to, num_robots, pad);
+        from = to;
+    }
+    total_length
+}
+
+/// Fewest presses on the outermost pad to move from key `from` to key `to`
+/// on `pads[pad]` and press it.
+fn press_len(
+    memo: &mut PressCache,
+    pads: &[Pad],
+    from: char,
+    to: char,
+    num_robots: usize,
+    pad: usize,
+) -> usize {
+    memo.get_or_compute(&(from, to, num_robots, pad), |memo| {
+        let all_paths = get_directions_to_target(&pads[pad][&from], &pads[pad][&to], &pads[pad]);
+        all_paths
+            .into_iter()
+            .map(|path| {
+                if num_robots == 0 {
+                    path.len()
+                } else {
+                    sequence_len(memo, pads, path, num_robots - 1, 1)
+                }
+            })
+            .min()
+            .unwrap()
+    })
+}
+
+impl Solution for Day21 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(codes: &Self::Parsed) -> Answer {
+        solve(codes, 2).into()
+    }
+
+    fn part2(codes: &Self::Parsed) -> Answer {
+        solve(codes, 25).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"029A
+980A
+179A
+456A
+379A"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve(&parse(INPUT_EX1).unwrap(), 2), 126384);
+    }
+
+    #[test]
+    fn parse_test() {
+        let codes = parse(&format!("{}\r\n\n", INPUT_EX1.replace('\n', "\r\n"))).unwrap();
+        assert_eq!(codes.len(), 5);
+        assert_eq!(codes[4], "379A");
+    }
+}
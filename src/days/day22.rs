@@ -0,0 +1,174 @@
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::{error::ParseError, parse::lines, Answer, Solution};
+
+pub struct Day22;
+
+fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    lines(input)
+        .map(|line| line.ints_exact().map(|[secret]| secret))
+        .collect()
+}
+
+fn solve_p1(xs: &[usize], count: usize) -> usize {
+    let mut sum = 0;
+    for &x in xs {
+        sum += generate(x, count)
+    }
+
+    sum
+}
+
+fn generate(mut x: usize, count: usize) -> usize {
+    for _ in 0..count {
+        x = generate_next(x);
+    }
+    x
+}
+
+fn generate_next(secret: usize) -> usize {
+    let secret = prune(mix(secret, secret * 64));
+    let secret = prune(mix(secret, secret / 32));
+
+    prune(mix(secret, secret * 2048))
+}
+
+fn mix(x_1: usize, x_2: usize) -> usize {
+    x_1 ^ x_2
+}
+
+fn prune(x: usize) -> usize {
+    x % 16777216
+}
+
+fn generate_next_seq(x: usize) -> (usize, isize, isize) {
+    let digit1 = (x % 10) as isize;
+
+    let x2 = generate_next(x);
+    let digit2 = (x2 % 10) as isize;
+    let diff = digit2 - digit1;
+
+    (x2, digit2, diff)
+}
+
+fn generate2(mut x: usize, count: usize) -> Vec<(usize, isize, Option<isize>)> {
+    let mut price_change_list = vec![(x, (x % 10) as isize, None)];
+    for _ in 0..count {
+        let (x2, digit2, diff) = generate_next_seq(x);
+        price_change_list.push((x2, digit2, Some(diff)));
+        x = x2;
+    }
+    price_change_list
+}
+
+type BuyerSeq = (usize, Vec<(usize, isize, isize)>);
+
+fn solve_p2(xs: &[usize], count: usize) -> usize {
+    let mut buyer_seqs: Vec<BuyerSeq> = Vec::new();
+
+    for &x in xs {
+        let seq = generate2(x, count);
+        let seq: Vec<_> = seq[1..]
+            .iter()
+            .map(|(x, y, z)| (*x, *y, z.unwrap()))
+            .collect();
+        buyer_seqs.push((x, seq));
+    }
+
+    let mut all_seqs = HashSet::new();
+    for buyer_seq in &buyer_seqs {
+        for w in buyer_seq.1.windows(4) {
+            let dw: Vec<isize> = w.iter().map(|(_, _, d)| *d).collect();
+            all_seqs.insert(dw);
+        }
+    }
+
+    log::info!("Number of sequences to try: {}", all_seqs.len());
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let buyer_seqs = Arc::new(buyer_seqs);
+
+    let all_seqs: Vec<_> = all_seqs.into_iter().collect();
+    let best_total_price = all_seqs
+        .into_par_iter()
+        .map(|seq| {
+            let current = counter.fetch_add(1, Ordering::Relaxed) + 1;
+            if current.is_multiple_of(100) {
+                log::debug!("Try sequence {}.", current);
+            }
+
+            let buyer_seqs = buyer_seqs.as_ref();
+            let mut total_price = 0;
+
+            for buyer_seq in buyer_seqs.iter() {
+                for buyer_seq_slice in buyer_seq.1.windows(4) {
+                    let diff_slice: Vec<_> = buyer_seq_slice.iter().map(|(_, _, d)| *d).collect();
+                    if diff_slice == seq {
+                        let price = buyer_seq_slice[3].1 as usize;
+                        total_price += price;
+                        break;
+                    }
+                }
+            }
+
+            total_price
+        })
+        .max()
+        .unwrap();
+
+    best_total_price
+}
+
+impl Solution for Day22 {
+    type Parsed = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(secrets: &Self::Parsed) -> Answer {
+        solve_p1(secrets, 2000).into()
+    }
+
+    fn part2(secrets: &Self::Parsed) -> Answer {
+        log::warn!("This is a slow solution. See v2.");
+        solve_p2(secrets, 2000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"1
+10
+100
+2024"#;
+
+    const INPUT_EX2: &str = r#"1
+2
+3
+2024"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(mix(42, 15), 37);
+        assert_eq!(prune(100000000), 16113920);
+        assert_eq!(generate(123, 1), 15887950);
+        assert_eq!(generate(123, 5), 1553684);
+        assert_eq!(generate(123, 10), 5908254);
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap(), 2000), 37327623);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(generate2(123, 0).last().unwrap(), &(123, 3, None));
+        assert_eq!(generate2(123, 1).last().unwrap(), &(15887950, 0, Some(-3)));
+        assert_eq!(generate2(123, 2).last().unwrap(), &(16495136, 6, Some(6)));
+        assert_eq!(generate2(123, 9).last().unwrap(), &(7753432, 2, Some(-2)));
+        assert_eq!(solve_p2(&parse(INPUT_EX2).unwrap(), 2000), 23);
+    }
+}
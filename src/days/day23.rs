@@ -0,0 +1,201 @@
+use itertools::Itertools;
+use std::{collections::HashMap, collections::HashSet};
+
+use crate::{error::ParseError, parse::lines, Answer, Solution};
+
+pub struct Day23;
+
+fn parse(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (n1, n2) = line
+                .text
+                .trim()
+                .split_once("-")
+                .ok_or_else(|| line.error("expected a connection like kh-tc"))?;
+            Ok((n1.to_string(), n2.to_string()))
+        })
+        .collect()
+}
+
+fn solve_p1(nss: &[(String, String)], name_start: char) -> usize {
+    let mut connections: HashMap<String, HashSet<String>> = HashMap::new();
+    for (ns1, ns2) in nss {
+        connections
+            .entry(ns1.clone())
+            .or_default()
+            .insert(ns2.clone());
+        connections
+            .entry(ns2.clone())
+            .or_default()
+            .insert(ns1.clone());
+    }
+
+    let mut sets = HashSet::new();
+    for (p1, p1ts) in &connections {
+        for p in p1ts.iter().permutations(2) {
+            let p2 = p[0];
+            let p3 = p[1];
+            let p2ts = &connections[p2];
+            let p3ts = &connections[p3];
+            if p2ts.contains(p1) && p2ts.contains(p3) && p3ts.contains(p1) && p3ts.contains(p2) {
+                let mut set = vec![p1, p2, p3];
+                set.sort();
+                sets.insert(set);
+            }
+        }
+    }
+
+    let name_start = name_start.to_string();
+
+    let sets = sets
+        .into_iter()
+        .filter(|set| set.iter().find(|p| p.starts_with(&name_start)).is_some())
+        .collect::<HashSet<_>>();
+
+    sets.len()
+}
+
+fn find_maximum_clique(graph: &HashMap<String, HashSet<String>>) -> Option<HashSet<String>> {
+    let mut max_clique = None;
+    let r = HashSet::new();
+    let p: HashSet<_> = graph.keys().cloned().collect();
+    let x = HashSet::new();
+
+    bron_kerbosch(graph, r, p, x, &mut max_clique);
+    max_clique
+}
+
+// https://en.wikipedia.org/wiki/Bron%E2%80%93Kerbosch_algorithm
+// Bron-Kerbosch with pivoting modified to find only the largest maximum clique
+fn bron_kerbosch(
+    graph: &HashMap<String, HashSet<String>>,
+    r: HashSet<String>,
+    p: HashSet<String>,
+    mut x: HashSet<String>,
+    max_clique: &mut Option<HashSet<String>>,
+) {
+    if p.is_empty() && x.is_empty() {
+        *max_clique = Some(max_clique.as_ref().map_or(r.clone(), |curr| {
+            if r.len() > curr.len() {
+                r.clone()
+            } else {
+                curr.clone()
+            }
+        }));
+        return;
+    }
+
+    let pivot = p
+        .union(&x)
+        .max_by_key(|v| p.intersection(&graph[*v]).count())
+        .cloned();
+
+    let vertices: Vec<_> = match pivot {
+        Some(u) => p.difference(&graph[&u]).cloned().collect(),
+        None => p.iter().cloned().collect(),
+    };
+
+    for v in vertices {
+        let neighbors = &graph[&v];
+
+        let mut new_r = r.clone();
+        new_r.insert(v.clone());
+
+        let new_p: HashSet<_> = p.intersection(neighbors).cloned().collect();
+        let new_x: HashSet<_> = x.intersection(neighbors).cloned().collect();
+
+        bron_kerbosch(graph, new_r, new_p, new_x, max_clique);
+
+        x.insert(v);
+    }
+}
+
+fn solve_p2(nss: &[(String, String)]) -> String {
+    let mut connections: HashMap<String, HashSet<String>> = HashMap::new();
+    for (ns1, ns2) in nss {
+        connections
+            .entry(ns1.clone())
+            .or_default()
+            .insert(ns2.clone());
+        connections
+            .entry(ns2.clone())
+            .or_default()
+            .insert(ns1.clone());
+    }
+
+    let largest_common = find_maximum_clique(&connections).expect("No maximum clique found");
+    let mut largest_common = largest_common.into_iter().collect::<Vec<_>>();
+    largest_common.sort();
+    largest_common.join(",")
+}
+
+impl Solution for Day23 {
+    type Parsed = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(connections: &Self::Parsed) -> Answer {
+        solve_p1(connections, 't').into()
+    }
+
+    fn part2(connections: &Self::Parsed) -> Answer {
+        solve_p2(connections).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap(), 't'), 7);
+    }
+
+    #[test]
+    fn p2_test1() {
+        assert_eq!(solve_p2(&parse(INPUT_EX1).unwrap()), "co,de,ka,ta");
+    }
+
+    #[test]
+    fn trailing_blank_line_test() {
+        let input = format!("{}\n\n", INPUT_EX1);
+        assert_eq!(solve_p1(&parse(&input).unwrap(), 't'), 7);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    error::ParseError,
+    parse::{grid, sections},
+    Answer, Solution,
+};
+
+pub struct Day25;
+
+type Heights = Vec<i64>;
+
+fn parse(input: &str) -> Result<(Vec<Heights>, Vec<Heights>), ParseError> {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+
+    let mut width = None;
+    for section in sections(input) {
+        for line in &section {
+            if let Some(x) = line.text.trim_end().find(|c| c != '#' && c != '.') {
+                return Err(line.error_at(&line.text[x..], "expected '#' or '.'"));
+            }
+        }
+        let schematic = grid(&section)?;
+        if *width.get_or_insert(schematic.w) != schematic.w {
+            return Err(section[0].error("all schematics must have the same width"));
+        }
+
+        // the first row is all '#' for locks, the last one for keys
+        let heights: Heights = schematic
+            .columns()
+            .map(|column| column.skip(1).filter(|&&c| c == '#').count() as i64)
+            .collect();
+        if schematic.row(0)[0] == '#' {
+            locks.push(heights);
+        } else {
+            keys.push(heights.into_iter().map(|h| h - 1).collect());
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+fn check_key(key: &[i64], lock: &[i64]) -> bool {
+    for i in 0..key.len() {
+        let key_height = key[i];
+        let lock_free_height = 5 - lock[i];
+        if key_height > lock_free_height {
+            return false;
+        }
+    }
+    true
+}
+
+fn solve_p1((locks, keys): &(Vec<Heights>, Vec<Heights>)) -> usize {
+    let mut fit_count = 0;
+    for lock in locks {
+        for key in keys {
+            let b = check_key(key, lock);
+            if b {
+                fit_count += 1;
+            }
+        }
+    }
+
+    fit_count
+}
+
+impl Solution for Day25 {
+    type Parsed = (Vec<Heights>, Vec<Heights>);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(schematics: &Self::Parsed) -> Answer {
+        solve_p1(schematics).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_EX1: &str = r#"#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####"#;
+
+    #[test]
+    fn p1_test1() {
+        assert_eq!(solve_p1(&parse(INPUT_EX1).unwrap()), 3);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::Solver;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day22_v2;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// A registered puzzle solution.
+pub struct Day {
+    pub day: u32,
+    /// Alternative implementation of the same day, e.g. "v2".
+    pub variant: Option<&'static str>,
+    pub solver: &'static dyn Solver,
+}
+
+impl Day {
+    /// Default input file name, relative to the working directory.
+    pub fn input_file(&self) -> String {
+        format!("input{:02}.txt", self.day)
+    }
+
+    /// Module-style name, e.g. "day22" or "day22_v2".
+    pub fn name(&self) -> String {
+        match self.variant {
+            Some(v) => format!("day{:02}_{}", self.day, v),
+            None => format!("day{:02}", self.day),
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        variant: None,
+        solver: &day01::Day01,
+    },
+    Day {
+        day: 2,
+        variant: None,
+        solver: &day02::Day02,
+    },
+    Day {
+        day: 3,
+        variant: None,
+        solver: &day03::Day03,
+    },
+    Day {
+        day: 4,
+        variant: None,
+        solver: &day04::Day04,
+    },
+    Day {
+        day: 5,
+        variant: None,
+        solver: &day05::Day05,
+    },
+    Day {
+        day: 6,
+        variant: None,
+        solver: &day06::Day06,
+    },
+    Day {
+        day: 7,
+        variant: None,
+        solver: &day07::Day07,
+    },
+    Day {
+        day: 8,
+        variant: None,
+        solver: &day08::Day08,
+    },
+    Day {
+        day: 9,
+        variant: None,
+        solver: &day09::Day09,
+    },
+    Day {
+        day: 10,
+        variant: None,
+        solver: &day10::Day10,
+    },
+    Day {
+        day: 11,
+        variant: None,
+        solver: &day11::Day11,
+    },
+    Day {
+        day: 12,
+        variant: None,
+        solver: &day12::Day12,
+    },
+    Day {
+        day: 13,
+        variant: None,
+        solver: &day13::Day13,
+    },
+    Day {
+        day: 14,
+        variant: None,
+        solver: &day14::Day14,
+    },
+    Day {
+        day: 15,
+        variant: None,
+        solver: &day15::Day15,
+    },
+    Day {
+        day: 16,
+        variant: None,
+        solver: &day16::Day16,
+    },
+    Day {
+        day: 17,
+        variant: None,
+        solver: &day17::Day17,
+    },
+    Day {
+        day: 18,
+        variant: None,
+        solver: &day18::Day18,
+    },
+    Day {
+        day: 19,
+        variant: None,
+        solver: &day19::Day19,
+    },
+    Day {
+        day: 20,
+        variant: None,
+        solver: &day20::Day20,
+    },
+    Day {
+        day: 21,
+        variant: None,
+        solver: &day21::Day21,
+    },
+    Day {
+        day: 22,
+        variant: None,
+        solver: &day22::Day22,
+    },
+    Day {
+        day: 22,
+        variant: Some("v2"),
+        solver: &day22_v2::Day22V2,
+    },
+    Day {
+        day: 23,
+        variant: None,
+        solver: &day23::Day23,
+    },
+    Day {
+        day: 24,
+        variant: None,
+        solver: &day24::Day24,
+    },
+    Day {
+        day: 25,
+        variant: None,
+        solver: &day25::Day25,
+    },
+];
+
+/// Looks up a day, `None` as variant selects the original solution.
+pub fn find(day: u32, variant: Option<&str>) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day && d.variant == variant)
+}
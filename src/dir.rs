@@ -0,0 +1,199 @@
+use crate::vec2::{Coord, Vec2, Vec2i};
+
+// Directions use screen coordinates: x grows to the right and y grows
+// downwards, so Up is (0, -1) and turning right goes clockwise on screen.
+
+/// One of the four orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn iter() -> impl Iterator<Item = Dir4> {
+        Self::ALL.into_iter()
+    }
+
+    /// Position in `ALL`, handy for indexing per-direction arrays.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn to_vec2i(self) -> Vec2i {
+        match self {
+            Dir4::Up => Vec2i::new(0, -1),
+            Dir4::Right => Vec2i::new(1, 0),
+            Dir4::Down => Vec2i::new(0, 1),
+            Dir4::Left => Vec2i::new(-1, 0),
+        }
+    }
+
+    pub fn from_vec2i(v: &Vec2i) -> Option<Dir4> {
+        Self::iter().find(|d| d.to_vec2i() == *v)
+    }
+
+    /// Parses one of `^`, `>`, `v` and `<`.
+    pub fn from_arrow_char(c: char) -> Option<Dir4> {
+        match c {
+            '^' => Some(Dir4::Up),
+            '>' => Some(Dir4::Right),
+            'v' => Some(Dir4::Down),
+            '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow_char(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+}
+
+impl<T: Coord> From<Dir4> for Vec2<T> {
+    fn from(dir: Dir4) -> Self {
+        let v = dir.to_vec2i();
+        Vec2::new(T::from(v.x as i8), T::from(v.y as i8))
+    }
+}
+
+/// One of the eight compass directions, in clockwise order starting north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Dir8> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns 45 degrees clockwise.
+    pub fn turn_right(self) -> Dir8 {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns 45 degrees counter-clockwise.
+    pub fn turn_left(self) -> Dir8 {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn to_vec2i(self) -> Vec2i {
+        match self {
+            Dir8::N => Vec2i::new(0, -1),
+            Dir8::NE => Vec2i::new(1, -1),
+            Dir8::E => Vec2i::new(1, 0),
+            Dir8::SE => Vec2i::new(1, 1),
+            Dir8::S => Vec2i::new(0, 1),
+            Dir8::SW => Vec2i::new(-1, 1),
+            Dir8::W => Vec2i::new(-1, 0),
+            Dir8::NW => Vec2i::new(-1, -1),
+        }
+    }
+
+    pub fn from_vec2i(v: &Vec2i) -> Option<Dir8> {
+        Self::iter().find(|d| d.to_vec2i() == *v)
+    }
+}
+
+impl<T: Coord> From<Dir8> for Vec2<T> {
+    fn from(dir: Dir8) -> Self {
+        let v = dir.to_vec2i();
+        Vec2::new(T::from(v.x as i8), T::from(v.y as i8))
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir.index() * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vec2::Vec2l;
+
+    #[test]
+    fn dir4_test() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        for d in Dir4::iter() {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.opposite().to_vec2i(), -1 * d.to_vec2i());
+            assert_eq!(Dir4::from_arrow_char(d.to_arrow_char()), Some(d));
+            assert_eq!(Dir4::from_vec2i(&d.to_vec2i()), Some(d));
+        }
+        assert_eq!(Dir4::from_arrow_char('x'), None);
+
+        // y grows downwards, so turning right from up faces +x
+        assert_eq!(Dir4::Up.turn_right().to_vec2i(), Vec2i::new(1, 0));
+        assert_eq!(Vec2i::new(2, 3).step(Dir4::Up, 3), Vec2i::new(2, 0));
+    }
+
+    #[test]
+    fn dir8_test() {
+        assert_eq!(Dir8::N.turn_right(), Dir8::NE);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::SE.opposite(), Dir8::NW);
+        for d in Dir8::iter() {
+            assert_eq!(d.opposite().to_vec2i(), -1 * d.to_vec2i());
+            assert_eq!(Dir8::from_vec2i(&d.to_vec2i()), Some(d));
+        }
+        for d in Dir4::iter() {
+            assert_eq!(Dir8::from(d).to_vec2i(), d.to_vec2i());
+        }
+        assert_eq!(Vec2i::new(0, 0).step(Dir8::SW, 2), Vec2i::new(-2, 2));
+        assert_eq!(
+            Vec2l::new(0, 0).step(Dir8::NE, 1 << 40),
+            Vec2l::new(1 << 40, -(1 << 40))
+        );
+    }
+}
@@ -0,0 +1,173 @@
+/// Union-find over the elements `0..n`, with union by rank.
+///
+/// Normally `find` also compresses paths. A set made with `with_rollback`
+/// skips the compression instead, so that unions can be undone in reverse
+/// order with `snapshot` and `rollback`.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    components: usize,
+    /// Unions as (attached root, new parent, whether the parent's rank grew),
+    /// kept only in rollback mode.
+    history: Option<Vec<(usize, usize, bool)>>,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            components: n,
+            history: None,
+        }
+    }
+
+    pub fn with_rollback(n: usize) -> Self {
+        DisjointSet {
+            history: Some(Vec::new()),
+            ..Self::new(n)
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Number of disjoint components.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Representative of the component holding `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        if self.history.is_none() {
+            let mut x = x;
+            while self.parent[x] != root {
+                let next = self.parent[x];
+                self.parent[x] = root;
+                x = next;
+            }
+        }
+        root
+    }
+
+    /// Joins the components of `a` and `b`. Returns false when they already
+    /// were one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            (a, b) = (b, a);
+        }
+        let rank_grew = self.rank[a] == self.rank[b];
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        if rank_grew {
+            self.rank[a] += 1;
+        }
+        self.components -= 1;
+        if let Some(history) = &mut self.history {
+            history.push((b, a, rank_grew));
+        }
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the component holding `x`.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Sizes of all components, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// Marks the current state for `rollback`. Panics unless the set was
+    /// made with `with_rollback`.
+    pub fn snapshot(&self) -> usize {
+        self.history
+            .as_ref()
+            .expect("snapshot needs a set made with with_rollback")
+            .len()
+    }
+
+    /// Undoes all unions made since `snapshot` returned `mark`.
+    pub fn rollback(&mut self, mark: usize) {
+        let history = self
+            .history
+            .as_mut()
+            .expect("rollback needs a set made with with_rollback");
+        while history.len() > mark {
+            let (child, parent, rank_grew) = history.pop().unwrap();
+            self.parent[child] = child;
+            self.size[parent] -= self.size[child];
+            if rank_grew {
+                self.rank[parent] -= 1;
+            }
+            self.components += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disjoint_set_test() {
+        let mut set = DisjointSet::new(6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert!(set.same(0, 3));
+        assert!(!set.same(0, 4));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.components(), 3);
+        assert_eq!(set.component_sizes(), vec![4, 1, 1]);
+    }
+
+    #[test]
+    fn rollback_test() {
+        let mut set = DisjointSet::with_rollback(5);
+        set.union(0, 1);
+        let mark = set.snapshot();
+        set.union(1, 2);
+        set.union(3, 4);
+        set.union(0, 4);
+        assert_eq!((set.components(), set.size(3)), (1, 5));
+
+        set.rollback(mark);
+        assert_eq!(set.components(), 4);
+        assert!(set.same(0, 1));
+        assert!(!set.same(1, 2));
+        assert!(!set.same(3, 4));
+        assert_eq!(set.component_sizes(), vec![2, 1, 1, 1]);
+
+        set.rollback(0);
+        assert_eq!(set.components(), 5);
+    }
+}
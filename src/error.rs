@@ -0,0 +1,125 @@
+use std::{fmt, str::FromStr};
+
+/// Error from parsing puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by whoever knows which day's input this was.
+    pub day: Option<u32>,
+    /// One-based line number.
+    pub line: usize,
+    /// One-based column, in characters.
+    pub column: usize,
+    /// The whole offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// `line` and `column` are zero-based, as from `enumerate()` and
+    /// `str::find`. The column is a byte offset into `text`.
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        let column = text
+            .get(..column)
+            .map_or(column, |before| before.chars().count());
+        ParseError {
+            day: None,
+            line: line + 1,
+            column: column + 1,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error about a whole line, reported at its first column.
+    pub fn line(line: usize, text: &str, message: impl Into<String>) -> Self {
+        Self::new(line, 0, text, message)
+    }
+
+    /// Error about `part`, which must be a slice of `text`.
+    pub fn at(line: usize, text: &str, part: &str, message: impl Into<String>) -> Self {
+        Self::new(line, offset_in(text, part), text, message)
+    }
+
+    pub fn with_day(mut self, day: u32) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// The offending line with a caret under the error column.
+    pub fn snippet(&self) -> String {
+        let number = self.line.to_string();
+        let pad = " ".repeat(number.len());
+        format!(
+            "{} |\n{} | {}\n{} | {}^",
+            pad,
+            number,
+            self.text,
+            pad,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset of `part` within `text`, or 0 when it is not a slice of it.
+fn offset_in(text: &str, part: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let p = part.as_ptr() as usize;
+    if p >= start && p + part.len() <= start + text.len() {
+        p - start
+    } else {
+        0
+    }
+}
+
+/// Parses `part`, a slice of line `text`, reporting failures at its position.
+pub fn parse_at<T>(line: usize, text: &str, part: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    part.parse().map_err(|e| {
+        ParseError::at(
+            line,
+            text,
+            part,
+            format!("invalid number {:?}: {}", part, e),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_test() {
+        let text = "47|5x";
+        let e = parse_at::<i64>(2, text, &text[3..])
+            .unwrap_err()
+            .with_day(5);
+        assert_eq!((e.line, e.column), (3, 4));
+        assert_eq!(
+            e.to_string(),
+            "day 5, line 3, column 4: invalid number \"5x\": invalid digit found in string"
+        );
+        assert_eq!(e.snippet(), "  |\n3 | 47|5x\n  |    ^");
+
+        assert_eq!(parse_at::<i64>(0, text, "47"), Ok(47));
+        assert_eq!(ParseError::line(0, text, "bad").column, 1);
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::map::Grid;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// RGB raster, usually drawn from a grid with one square block per cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub w: u32,
+    pub h: u32,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Draws each cell as a `scale` by `scale` block coloured by `palette`.
+    /// Fails when the image would be too large to address.
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        scale: u32,
+        palette: impl Fn(&T) -> Rgb,
+    ) -> io::Result<Self> {
+        let too_large = || invalid_input("image too large");
+        let w = (grid.w as u32).checked_mul(scale).ok_or_else(too_large)?;
+        let h = (grid.h as u32).checked_mul(scale).ok_or_else(too_large)?;
+        let len = (w as usize)
+            .checked_mul(h as usize)
+            .and_then(|n| n.checked_mul(3))
+            .ok_or_else(too_large)?;
+        let mut pixels = Vec::with_capacity(len);
+        for row in grid.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| palette(cell).repeat(scale as usize))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Ok(Image { w, h, pixels })
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Rgb {
+        let i = (y as usize * self.w as usize + x as usize) * 3;
+        [self.pixels[i], self.pixels[i + 1], self.pixels[i + 2]]
+    }
+
+    /// Binary PPM (P6), which most image viewers open directly.
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.w, self.h)?;
+        out.write_all(&self.pixels)?;
+        out.flush()
+    }
+
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(&mut out, self.w, self.h);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        out.flush()
+    }
+
+    /// Writes a .ppm or .png file, picked by the extension of `path`.
+    /// Nothing is created for other extensions.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let create = || File::create(path).map(BufWriter::new);
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("ppm") => self.write_ppm(create()?),
+            Some("png") => self.write_png(create()?),
+            _ => Err(invalid_input(&format!(
+                "unsupported image format: {}",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Writes `frames` as a looping animated GIF, showing each for `delay`
+/// hundredths of a second. All frames must have the size of the first.
+///
+/// Frames can come straight from a simulation, e.g.
+/// `iter::from_fn(|| { step(&mut state); Some(draw(&state)) }).take(100)`.
+pub fn write_gif(
+    out: impl Write,
+    delay: u16,
+    frames: impl IntoIterator<Item = Image>,
+) -> io::Result<()> {
+    let mut frames = frames.into_iter().peekable();
+    let Some(first) = frames.peek() else {
+        return Err(invalid_input("a GIF needs at least one frame"));
+    };
+    let (w, h) = (first.w, first.h);
+    let (Ok(gif_w), Ok(gif_h)) = (u16::try_from(w), u16::try_from(h)) else {
+        return Err(invalid_input("image too large for a GIF"));
+    };
+
+    let mut encoder = gif::Encoder::new(out, gif_w, gif_h, &[]).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for image in frames {
+        if (image.w, image.h) != (w, h) {
+            return Err(invalid_input("all GIF frames must have the same size"));
+        }
+        let mut frame = gif::Frame::from_rgb_speed(gif_w, gif_h, &image.pixels, 10);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    encoder.into_inner()?.flush()
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::read_map;
+
+    #[test]
+    fn image_test() {
+        let map = read_map("#.\n..");
+        let image = Image::from_grid(&map, 2, |&c| if c == '#' { WHITE } else { BLACK }).unwrap();
+        assert_eq!((image.w, image.h), (4, 4));
+        assert_eq!(image.pixel(1, 1), WHITE);
+        assert_eq!(image.pixel(2, 1), BLACK);
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        assert!(image.write_ppm(FailingFlush).is_err());
+        assert!(image.write_png(FailingFlush).is_err());
+
+        let jpg = std::env::temp_dir().join(format!("image_test_{}.jpg", std::process::id()));
+        assert!(image.save(&jpg).is_err());
+        assert!(!jpg.exists());
+
+        assert!(Image::from_grid(&map, u32::MAX, |_| BLACK).is_err());
+    }
+
+    /// Accepts every write but fails to flush.
+    struct FailingFlush;
+
+    impl Write for FailingFlush {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::Error::other("flush failed"))
+        }
+    }
+
+    #[test]
+    fn gif_test() {
+        let frames = (0..3).map(|i| {
+            let grid = Grid::from_fn(3, 1, |p| p.x == i);
+            Image::from_grid(&grid, 1, |&on| if on { WHITE } else { BLACK }).unwrap()
+        });
+        let mut gif = Vec::new();
+        write_gif(&mut gif, 5, frames).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(gif.last(), Some(&b';'));
+
+        let small = Image::from_grid(&Grid::new(1, 1, false), 1, |_| BLACK).unwrap();
+        let large = Image::from_grid(&Grid::new(2, 1, false), 1, |_| BLACK).unwrap();
+        assert!(write_gif(Vec::new(), 5, [small, large]).is_err());
+        assert!(write_gif(Vec::new(), 5, []).is_err());
+    }
+}
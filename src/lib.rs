@@ -1,2 +1,147 @@
+use std::{any::Any, env, fmt, fs};
+
+use error::ParseError;
+
+pub mod cycle;
+pub mod days;
+pub mod dir;
+pub mod disjoint_set;
+pub mod error;
+pub mod image;
+pub mod linear;
 pub mod map;
-pub mod vec2i;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod regions;
+pub mod render;
+pub mod search;
+pub mod sparse;
+pub mod vec2;
+/// Old home of `Vec2i`, kept so that existing imports still compile.
+pub mod vec2i {
+    #[deprecated(note = "use `vec2::Vec2i` instead")]
+    pub type Vec2i = crate::vec2::Vec2i;
+}
+pub mod vec3;
+pub mod vecn;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// The puzzle has no such part, like day 25 part 2.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A day's puzzle: input is parsed once and shared by both parts.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed) -> Answer;
+
+    fn part2(_parsed: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// Object-safe view of a `Solution`, so that all days fit in one table.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part1(&self, parsed: &dyn Any) -> Answer;
+
+    fn part2(&self, parsed: &dyn Any) -> Answer;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Parsed: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        S::parse(input).map(|parsed| Box::new(parsed) as Box<dyn Any>)
+    }
+
+    fn part1(&self, parsed: &dyn Any) -> Answer {
+        S::part1(
+            parsed
+                .downcast_ref()
+                .expect("input parsed by another solution"),
+        )
+    }
+
+    fn part2(&self, parsed: &dyn Any) -> Answer {
+        S::part2(
+            parsed
+                .downcast_ref()
+                .expect("input parsed by another solution"),
+        )
+    }
+}
+
+/// Shared `main` of the dayNN binaries. Solves the file named by the first
+/// argument, or inputNN.txt when there is none.
+pub fn run_main<S: Solution>(day: u32) {
+    env_logger::init();
+
+    let input_file = env::args().nth(1).unwrap_or(format!("input{:02}.txt", day));
+    let input = fs::read_to_string(&input_file).unwrap_or_else(|_| {
+        eprintln!("Error: Unable to read file '{}'", input_file);
+        std::process::exit(1);
+    });
+
+    let parsed = S::parse(&input).unwrap_or_else(|e| {
+        let e = e.with_day(day);
+        eprintln!(
+            "Error: Unable to parse '{}': {}\n{}",
+            input_file,
+            e,
+            e.snippet()
+        );
+        std::process::exit(1);
+    });
+
+    println!("Part1: {}", S::part1(&parsed));
+    let part2 = S::part2(&parsed);
+    if part2 != Answer::Unsolved {
+        println!("Part2: {}", part2);
+    }
+}
@@ -0,0 +1,476 @@
+use std::fmt;
+
+use crate::math::{crt, gcd, mod_inv};
+
+/// Most combinations of free variables `min_cost_solution` will enumerate.
+pub const SEARCH_LIMIT: u64 = 1 << 24;
+
+/// Why `min_cost_solution` gave up on a system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unsupported {
+    /// More than one free variable has no upper bound.
+    UnboundedFree,
+    /// Enumerating the free variables would take more than `SEARCH_LIMIT`
+    /// steps.
+    SearchTooLarge,
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unsupported::UnboundedFree => write!(f, "more than one unbounded free variable"),
+            Unsupported::SearchTooLarge => write!(f, "too many free variable combinations"),
+        }
+    }
+}
+
+impl std::error::Error for Unsupported {}
+
+/// Non-negative integer `x` with `A·x = b` that minimises `cost·x`, along
+/// with that cost. `a` holds the rows of `A`. `Ok(None)` when there is no
+/// solution or the cost has no minimum.
+///
+/// Singular systems are handled by searching the free variables: the last
+/// one is solved exactly, and all others are enumerated. That needs each
+/// of them bounded, by a row of `A` with no negative entries or by `max`,
+/// and is only done for up to `SEARCH_LIMIT` combinations. In practice this
+/// means one free variable at any scale, or several with small bounds.
+pub fn min_cost_solution(
+    a: &[Vec<i64>],
+    b: &[i64],
+    cost: &[i64],
+) -> Result<Option<(Vec<i64>, i64)>, Unsupported> {
+    min_cost_solution_within(a, b, cost, &vec![None; cost.len()])
+}
+
+/// Like `min_cost_solution`, but also requires `x[i] <= max[i]` wherever
+/// `max[i]` is set.
+pub fn min_cost_solution_within(
+    a: &[Vec<i64>],
+    b: &[i64],
+    cost: &[i64],
+    max: &[Option<i64>],
+) -> Result<Option<(Vec<i64>, i64)>, Unsupported> {
+    let n = cost.len();
+    assert_eq!(a.len(), b.len(), "one right-hand side per row");
+    assert!(a.iter().all(|row| row.len() == n), "one cost per column");
+    assert_eq!(max.len(), n, "one maximum per column");
+
+    let Some(mut echelon) = Echelon::reduce(a, b) else {
+        return Ok(None);
+    };
+    echelon.max = max.iter().map(|m| m.map(i128::from)).collect();
+    let mut bounds = Vec::new();
+    for &f in &echelon.free {
+        // A column that no row uses is best left at 0, unless it pays to
+        // press it as often as allowed.
+        let unused = echelon.rows.iter().all(|row| row[f] == 0);
+        let bound = match unused {
+            true if cost[f] < 0 => echelon.max[f],
+            true => Some(0),
+            false => free_bound(a, b, f),
+        };
+        bounds.push(match (bound, echelon.max[f]) {
+            (Some(bound), Some(max)) => Some(bound.min(max)),
+            (bound, max) => bound.or(max),
+        });
+    }
+    // The last free variable is solved exactly, so it may be the unbounded one.
+    let mut order: Vec<usize> = (0..bounds.len()).collect();
+    order.sort_by_key(|&i| bounds[i].is_none());
+    echelon.free = order.iter().map(|&i| echelon.free[i]).collect();
+    let mut limits = Vec::new();
+    let mut steps: u64 = 1;
+    for &i in order.iter().take(order.len().saturating_sub(1)) {
+        let limit = bounds[i].ok_or(Unsupported::UnboundedFree)?;
+        steps = steps.saturating_mul(u64::try_from(limit + 1).unwrap_or(u64::MAX));
+        limits.push(limit);
+    }
+    if steps > SEARCH_LIMIT {
+        return Err(Unsupported::SearchTooLarge);
+    }
+    let cost: Vec<i128> = cost.iter().map(|&c| c as i128).collect();
+
+    let mut best = None;
+    echelon.search(&cost, &limits, &mut Vec::new(), &mut best);
+    let Some((x, total)) = best else {
+        return Ok(None);
+    };
+    let x: Option<Vec<i64>> = x.into_iter().map(|v| i64::try_from(v).ok()).collect();
+    Ok(x.zip(i64::try_from(total).ok()))
+}
+
+/// Largest value column `f` can take, from a row without negative entries.
+fn free_bound(a: &[Vec<i64>], b: &[i64], f: usize) -> Option<i128> {
+    a.iter()
+        .zip(b)
+        .filter(|(row, &rhs)| row[f] > 0 && rhs >= 0 && row.iter().all(|&v| v >= 0))
+        .map(|(row, &rhs)| (rhs / row[f]) as i128)
+        .min()
+}
+
+/// `[A | b]` in reduced row echelon form, kept integral by scaling rows
+/// instead of dividing them (fraction-free elimination).
+struct Echelon {
+    /// Non-zero rows, each `n` coefficients followed by the right-hand side.
+    rows: Vec<Vec<i128>>,
+    /// Pivot column of each row.
+    pivots: Vec<usize>,
+    /// Columns without a pivot.
+    free: Vec<usize>,
+    /// Upper bound of each column, if any.
+    max: Vec<Option<i128>>,
+}
+
+impl Echelon {
+    /// `None` when the system is inconsistent.
+    fn reduce(a: &[Vec<i64>], b: &[i64]) -> Option<Self> {
+        let n = a.first().map_or(0, |row| row.len());
+        let mut rows: Vec<Vec<i128>> = a
+            .iter()
+            .zip(b)
+            .map(|(row, &rhs)| row.iter().chain([&rhs]).map(|&v| v as i128).collect())
+            .collect();
+        let mut pivots = Vec::new();
+        let mut free = Vec::new();
+
+        for col in 0..n {
+            let r = pivots.len();
+            let Some(found) = (r..rows.len()).find(|&i| rows[i][col] != 0) else {
+                free.push(col);
+                continue;
+            };
+            rows.swap(r, found);
+            normalise(&mut rows[r]);
+            let pivot_row = rows[r].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                let factor = row[col];
+                if i != r && factor != 0 {
+                    for (v, &pv) in row.iter_mut().zip(&pivot_row) {
+                        *v = *v * pivot_row[col] - pv * factor;
+                    }
+                    normalise(row);
+                }
+            }
+            pivots.push(col);
+        }
+
+        // Rows below the pivots have no coefficients left: 0 = rhs.
+        if rows[pivots.len()..].iter().any(|row| row[n] != 0) {
+            return None;
+        }
+        rows.truncate(pivots.len());
+        Some(Echelon {
+            rows,
+            pivots,
+            free,
+            max: vec![None; n],
+        })
+    }
+
+    fn width(&self) -> usize {
+        self.pivots.len() + self.free.len()
+    }
+
+    /// Right-hand side of each row once the free variables in `fixed` are
+    /// moved over.
+    fn rest(&self, fixed: &[i128]) -> Vec<i128> {
+        let n = self.width();
+        self.rows
+            .iter()
+            .map(|row| {
+                let moved: i128 = self.free.iter().zip(fixed).map(|(&f, &v)| row[f] * v).sum();
+                row[n] - moved
+            })
+            .collect()
+    }
+
+    /// The whole solution for the given free variables, if it is integral,
+    /// non-negative and within `max`.
+    fn assemble(&self, free_values: &[i128]) -> Option<Vec<i128>> {
+        let mut x = vec![0; self.width()];
+        for (&f, &v) in self.free.iter().zip(free_values) {
+            x[f] = v;
+        }
+        for ((row, &col), rest) in self
+            .rows
+            .iter()
+            .zip(&self.pivots)
+            .zip(self.rest(free_values))
+        {
+            let p = row[col];
+            if rest % p != 0 || rest / p < 0 || self.max[col].is_some_and(|m| rest / p > m) {
+                return None;
+            }
+            x[col] = rest / p;
+        }
+        Some(x)
+    }
+
+    fn search(
+        &self,
+        cost: &[i128],
+        limits: &[i128],
+        fixed: &mut Vec<i128>,
+        best: &mut Option<(Vec<i128>, i128)>,
+    ) {
+        let candidate = if self.free.is_empty() {
+            self.assemble(&[])
+        } else if fixed.len() < limits.len() {
+            for v in 0..=limits[fixed.len()] {
+                fixed.push(v);
+                self.search(cost, limits, fixed, best);
+                fixed.pop();
+            }
+            return;
+        } else {
+            self.best_last(cost, fixed)
+        };
+
+        if let Some(x) = candidate {
+            let total: i128 = x.iter().zip(cost).map(|(&v, &c)| v * c).sum();
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_total)| total < *best_total)
+            {
+                *best = Some((x, total));
+            }
+        }
+    }
+
+    /// Cheapest solution over the last free variable `t`, with the others
+    /// fixed. Each row reads `p·x_pivot = rest - a·t`, so `t` must satisfy
+    /// one congruence and up to two inequalities per row. As the cost is
+    /// linear in `t`, the optimum is at the smallest or largest allowed value.
+    fn best_last(&self, cost: &[i128], fixed: &[i128]) -> Option<Vec<i128>> {
+        let t_col = *self.free.last().unwrap();
+        let rest = self.rest(fixed);
+
+        let mut congruences = Vec::new();
+        let (mut lo, mut hi) = (0, self.max[t_col]);
+        let mut at_most = |bound: i128| hi = Some(hi.map_or(bound, |hi: i128| hi.min(bound)));
+        for ((row, &col), rest) in self.rows.iter().zip(&self.pivots).zip(rest) {
+            let (p, a) = (row[col], row[t_col]);
+            congruences.push(linear_congruence(a, rest, p.abs())?);
+
+            // x_pivot >= 0 means a·t <= rest, with both sides scaled by sign(p).
+            let (sa, sr) = (a * p.signum(), rest * p.signum());
+            match sa.signum() {
+                1 => at_most(sr.div_euclid(sa)),
+                -1 => lo = lo.max(-sr.div_euclid(-sa)),
+                _ if sr < 0 => return None,
+                _ => {}
+            }
+            // x_pivot <= max means a·t >= rest - |p|·max, scaled the same way.
+            if let Some(max) = self.max[col] {
+                let sr = sr - p.abs() * max;
+                match sa.signum() {
+                    1 => lo = lo.max(-(-sr).div_euclid(sa)),
+                    -1 => at_most((-sr).div_euclid(-sa)),
+                    _ if sr > 0 => return None,
+                    _ => {}
+                }
+            }
+        }
+
+        let congruences: Vec<(i64, i64)> = congruences
+            .into_iter()
+            .map(|(r, m)| Some((i64::try_from(r).ok()?, i64::try_from(m).ok()?)))
+            .collect::<Option<_>>()?;
+        let (t0, m) = crt(&congruences)?;
+        let (t0, m) = (t0 as i128, m as i128);
+
+        let with_t = |t: i128| {
+            let mut values = fixed.to_vec();
+            values.push(t);
+            self.assemble(&values)
+        };
+        let total = |x: &Vec<i128>| -> i128 { x.iter().zip(cost).map(|(&v, &c)| v * c).sum() };
+
+        let first = lo + (t0 - lo).rem_euclid(m);
+        let last = match hi {
+            Some(hi) if first > hi => return None,
+            Some(hi) => hi - (hi - t0).rem_euclid(m),
+            None => {
+                // Unbounded above: fine unless the cost keeps falling.
+                let x = with_t(first)?;
+                return (total(&with_t(first + m)?) >= total(&x)).then_some(x);
+            }
+        };
+        let (x_first, x_last) = (with_t(first)?, with_t(last)?);
+        Some(if total(&x_last) < total(&x_first) {
+            x_last
+        } else {
+            x_first
+        })
+    }
+}
+
+/// Divides a row by the gcd of its entries and makes its leading entry
+/// positive.
+fn normalise(row: &mut [i128]) {
+    let g = row.iter().fold(0, |g, &v| gcd_i128(g, v));
+    let sign = row.iter().find(|&&v| v != 0).map_or(1, |v| v.signum());
+    if g > 1 || sign < 0 {
+        for v in row.iter_mut() {
+            *v /= g * sign;
+        }
+    }
+}
+
+fn gcd_i128(a: i128, b: i128) -> i128 {
+    match (i64::try_from(a), i64::try_from(b)) {
+        (Ok(a), Ok(b)) => gcd(a, b) as i128,
+        _ => {
+            let (mut a, mut b) = (a.abs(), b.abs());
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        }
+    }
+}
+
+/// All `t` with `a·t ≡ c (mod m)`, as `(t0, modulus)`.
+fn linear_congruence(a: i128, c: i128, m: i128) -> Option<(i128, i128)> {
+    let (a, c) = (a.rem_euclid(m), c.rem_euclid(m));
+    let g = gcd_i128(a, m);
+    if c % g != 0 {
+        return None;
+    }
+    let m = m / g;
+    let inv = mod_inv(i64::try_from(a / g).ok()?, i64::try_from(m).ok()?)? as i128;
+    Some(((c / g) * inv % m, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(a: &[Vec<i64>], b: &[i64], cost: &[i64]) -> Option<(Vec<i64>, i64)> {
+        min_cost_solution(a, b, cost).unwrap()
+    }
+
+    #[test]
+    fn unique_solution_test() {
+        let a = vec![vec![94, 22], vec![34, 67]];
+        assert_eq!(solve(&a, &[8400, 5400], &[3, 1]), Some((vec![80, 40], 280)));
+        assert_eq!(solve(&a, &[8401, 5400], &[3, 1]), None);
+        let negative = vec![vec![1, 0], vec![0, 1]];
+        assert_eq!(solve(&negative, &[3, -1], &[1, 1]), None);
+    }
+
+    #[test]
+    fn singular_test() {
+        // Both buttons move along the diagonal.
+        let a = vec![vec![4, 1], vec![4, 1]];
+        assert_eq!(solve(&a, &[10, 10], &[3, 1]), Some((vec![2, 2], 8)));
+        assert_eq!(solve(&a, &[10, 10], &[5, 1]), Some((vec![0, 10], 10)));
+        assert_eq!(solve(&a, &[10, 11], &[3, 1]), None);
+
+        let huge = 10_000_000_000_000;
+        let (x, _) = solve(&a, &[huge, huge], &[3, 1]).unwrap();
+        assert_eq!(x, vec![huge / 4, 0]);
+
+        // The only way to 7 with steps of 2 and 3 is 2 + 2 + 3.
+        let a = vec![vec![2, 3]];
+        assert_eq!(solve(&a, &[7], &[1, 1]), Some((vec![2, 1], 3)));
+        assert_eq!(solve(&a, &[1], &[1, 1]), None);
+
+        // Too many free variables to enumerate, unless they are bounded.
+        let a = vec![vec![1, 2, 3]];
+        assert_eq!(
+            min_cost_solution(&a, &[huge], &[1, 1, 1]),
+            Err(Unsupported::SearchTooLarge)
+        );
+        let max = [None, Some(10), Some(10)];
+        assert_eq!(
+            min_cost_solution_within(&a, &[30], &[1, 1, 1], &max),
+            Ok(Some((vec![0, 0, 10], 10)))
+        );
+        let a = vec![vec![1, -1, -1]];
+        assert_eq!(
+            min_cost_solution(&a, &[0], &[1, 1, 1]),
+            Err(Unsupported::UnboundedFree)
+        );
+    }
+
+    #[test]
+    fn brute_force_test() {
+        let mut seed = 1u64;
+        let mut random = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % n) as i64
+        };
+        for _ in 0..200 {
+            // Two rows, three buttons, sometimes with repeated columns.
+            let a: Vec<Vec<i64>> = (0..2)
+                .map(|_| (0..3).map(|_| random(4)).collect())
+                .collect();
+            let b: Vec<i64> = (0..2).map(|_| random(30)).collect();
+            let cost: Vec<i64> = (0..3).map(|_| random(5) + 1).collect();
+
+            let mut expected: Option<i64> = None;
+            for x0 in 0..=30 {
+                for x1 in 0..=30 {
+                    for x2 in 0..=30 {
+                        let x = [x0, x1, x2];
+                        let fits =
+                            (0..2).all(|i| (0..3).map(|j| a[i][j] * x[j]).sum::<i64>() == b[i]);
+                        if fits {
+                            let total = (0..3).map(|j| cost[j] * x[j]).sum();
+                            expected = Some(expected.map_or(total, |e: i64| e.min(total)));
+                        }
+                    }
+                }
+            }
+
+            let found = solve(&a, &b, &cost);
+            assert_eq!(found.map(|(_, total)| total), expected, "{a:?} x = {b:?}");
+        }
+    }
+
+    #[test]
+    fn bounded_brute_force_test() {
+        let mut seed = 7u64;
+        let mut random = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % n) as i64
+        };
+        for _ in 0..200 {
+            // One row, so that two of the three buttons are free.
+            let a = vec![(0..3).map(|_| random(4)).collect::<Vec<i64>>()];
+            let b = [random(40)];
+            let cost: Vec<i64> = (0..3).map(|_| random(5) + 1).collect();
+            let max: Vec<Option<i64>> = (0..3)
+                .map(|_| Some(random(12)).filter(|&m| m < 10))
+                .collect();
+
+            let mut expected: Option<i64> = None;
+            for x0 in 0..=40 {
+                for x1 in 0..=40 {
+                    for x2 in 0..=40 {
+                        let x = [x0, x1, x2];
+                        let fits = (0..3).map(|j| a[0][j] * x[j]).sum::<i64>() == b[0]
+                            && (0..3).all(|j| max[j].is_none_or(|m| x[j] <= m));
+                        if fits {
+                            let total = (0..3).map(|j| cost[j] * x[j]).sum();
+                            expected = Some(expected.map_or(total, |e: i64| e.min(total)));
+                        }
+                    }
+                }
+            }
+
+            let found = min_cost_solution_within(&a, &b, &cost, &max).unwrap();
+            assert_eq!(
+                found.map(|(_, total)| total),
+                expected,
+                "{a:?} x = {b:?}, max {max:?}"
+            );
+        }
+    }
+}
@@ -1,21 +1,29 @@
-use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
 
-use crate::vec2i::Vec2i;
+use crate::{dir::Dir4, error::ParseError, parse, vec2::Vec2i, vecn::Vector};
 
+/// Rectangular grid with row-major, contiguous cell storage.
 #[derive(PartialEq, Eq, Clone)]
-pub struct Map {
+pub struct Grid<T> {
     pub w: i32,
     pub h: i32,
-    pub m: Vec<Vec<char>>,
+    cells: Vec<T>,
 }
 
+/// Character grid as read from puzzle input.
+pub type Map = Grid<char>;
+
 impl fmt::Debug for Map {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Map {{")?;
         writeln!(f, "    w: {},", self.w)?;
         writeln!(f, "    h: {},", self.h)?;
         writeln!(f, "    m: [")?;
-        for row in &self.m {
+        for row in self.rows() {
             write!(f, "        ")?;
             for &cell in row {
                 write!(f, "{}", cell)?;
@@ -27,37 +35,540 @@ impl fmt::Debug for Map {
     }
 }
 
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Panics if `w` or `h` is negative.
+    pub fn new(w: i32, h: i32, fill: T) -> Self {
+        Grid {
+            w,
+            h,
+            cells: vec![fill; cell_count(w, h)],
+        }
+    }
+
+    fn remap(&self, w: i32, h: i32, f: impl Fn(Vec2i) -> Vec2i) -> Self {
+        Grid::from_fn(w, h, |p| self[f(p)].clone())
+    }
+
+    /// Swaps rows and columns, mirroring along the main diagonal.
+    pub fn transpose(&self) -> Self {
+        self.remap(self.h, self.w, |p| Vec2i::new(p.y, p.x))
+    }
+
+    /// Quarter turn clockwise on screen.
+    pub fn rotate_90(&self) -> Self {
+        let h = self.h;
+        self.remap(self.h, self.w, |p| Vec2i::new(p.y, h - 1 - p.x))
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let (w, h) = (self.w, self.h);
+        self.remap(w, h, |p| Vec2i::new(w - 1 - p.x, h - 1 - p.y))
+    }
+
+    /// Quarter turn counter-clockwise on screen.
+    pub fn rotate_270(&self) -> Self {
+        let w = self.w;
+        self.remap(self.h, self.w, |p| Vec2i::new(w - 1 - p.y, p.x))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_h(&self) -> Self {
+        let w = self.w;
+        self.remap(self.w, self.h, |p| Vec2i::new(w - 1 - p.x, p.y))
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_v(&self) -> Self {
+        let h = self.h;
+        self.remap(self.w, self.h, |p| Vec2i::new(p.x, h - 1 - p.y))
+    }
+
+    /// The cells within the inclusive corners `min` and `max`, clipped to
+    /// the grid.
+    pub fn crop(&self, min: Vec2i, max: Vec2i) -> Self {
+        let min = min.max_components(Vec2i::new(0, 0));
+        let max = max.min_components(Vec2i::new(self.w - 1, self.h - 1));
+        let size = (max - min + (1, 1)).max_components(Vec2i::new(0, 0));
+        self.remap(size.x, size.y, |p| p + min)
+    }
+
+    /// Repeats the grid `nx` times across and `ny` times down.
+    pub fn tile(&self, nx: i32, ny: i32) -> Self {
+        let (w, h) = (self.w, self.h);
+        self.remap(w * nx, h * ny, |p| Vec2i::new(p.x % w, p.y % h))
+    }
+}
+
+impl<T: Copy + PartialEq> Grid<T> {
+    /// First position holding `value`, in row-major order.
+    pub fn find(&self, value: T) -> Option<Vec2i> {
+        self.find_all(value).next()
+    }
+
+    /// All positions holding `value`, in row-major order.
+    pub fn find_all(&self, value: T) -> impl Iterator<Item = Vec2i> + '_ {
+        self.iter()
+            .filter(move |&(_, &cell)| cell == value)
+            .map(|(p, _)| p)
+    }
+}
+
 impl Map {
+    /// Error pointing at `p`. Assumes row y came from line y of the input,
+    /// which `parse_map` and `parse::grid_and_rest` guarantee.
+    pub fn error_at(&self, p: Vec2i, message: impl Into<String>) -> ParseError {
+        let row: String = self
+            .rows()
+            .nth(p.y.max(0) as usize)
+            .unwrap_or_default()
+            .iter()
+            .collect();
+        let column = row.char_indices().nth(p.x as usize).map_or(0, |(i, _)| i);
+        ParseError::new(p.y.max(0) as usize, column, &row, message)
+    }
+
+    /// The position of a marker that must appear exactly once.
+    pub fn find_unique(&self, c: char) -> Result<Vec2i, ParseError> {
+        let mut found = self.find_all(c);
+        let p = found
+            .next()
+            .ok_or_else(|| self.error_at(Vec2i::new(0, 0), format!("no '{}' on the map", c)))?;
+        if let Some(second) = found.next() {
+            return Err(self.error_at(second, format!("more than one '{}' on the map", c)));
+        }
+        Ok(p)
+    }
+
+    /// Like `find_unique`, then overwrites the marker with `replacement`.
+    pub fn take_marker(&mut self, c: char, replacement: char) -> Result<Vec2i, ParseError> {
+        let p = self.find_unique(c)?;
+        self[p] = replacement;
+        Ok(p)
+    }
+
+    /// Positions of every char on the map, each in row-major order.
+    pub fn positions_by_char(&self) -> HashMap<char, Vec<Vec2i>> {
+        let mut positions: HashMap<char, Vec<Vec2i>> = HashMap::new();
+        for (p, &c) in self.iter() {
+            positions.entry(c).or_default().push(p);
+        }
+        positions
+    }
+
+    /// Widens every cell into `k` cells, spelled out by `mapping`, e.g.
+    /// `'O'` into `"[]"`.
+    pub fn scale_x<'a>(&self, k: usize, mapping: impl Fn(char) -> &'a str) -> Map {
+        Grid::from_rows(
+            self.rows()
+                .map(|row| {
+                    row.iter()
+                        .flat_map(|&c| {
+                            let wide = mapping(c);
+                            assert_eq!(
+                                wide.chars().count(),
+                                k,
+                                "'{}' must map to {} chars, not {:?}",
+                                c,
+                                k,
+                                wide
+                            );
+                            wide.chars()
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<T> Grid<T> {
+    /// Panics if `w` or `h` is negative.
+    pub fn from_fn<F: FnMut(Vec2i) -> T>(w: i32, h: i32, mut f: F) -> Self {
+        cell_count(w, h);
+        let cells = (0..h)
+            .flat_map(|y| (0..w).map(move |x| Vec2i::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Grid { w, h, cells }
+    }
+
+    /// Builds a grid from rows of equal length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let h = rows.len() as i32;
+        let w = rows.first().map_or(0, |row| row.len()) as i32;
+        assert!(
+            rows.iter().all(|row| row.len() as i32 == w),
+            "all grid rows must have the same length"
+        );
+        let cells = rows.into_iter().flatten().collect();
+        Grid { w, h, cells }
+    }
+
     pub fn inside(&self, p: &Point) -> bool {
         p.x >= 0 && p.x < self.w && p.y >= 0 && p.y < self.h
     }
 
-    pub fn read<P: Into<Vec2i>>(&self, p: P) -> Option<char> {
-        let p2: Vec2i = p.into();
-        read_at(self, p2)
+    fn index_of(&self, p: &Vec2i) -> Option<usize> {
+        if self.inside(p) {
+            Some((p.y * self.w + p.x) as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get<P: Into<Vec2i>>(&self, p: P) -> Option<&T> {
+        let p: Vec2i = p.into();
+        self.index_of(&p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<P: Into<Vec2i>>(&mut self, p: P) -> Option<&mut T> {
+        let p: Vec2i = p.into();
+        self.index_of(&p).map(|i| &mut self.cells[i])
     }
 
-    pub fn write<P: Into<Vec2i>>(&mut self, p: P, c: char) {
-        let p2: Vec2i = p.into();
-        if self.inside(&p2) {
-            self.m[p2.y as usize][p2.x as usize] = c;
+    /// Sets the cell at `p`. Positions outside the grid are ignored.
+    pub fn set<P: Into<Vec2i>>(&mut self, p: P, value: T) {
+        if let Some(cell) = self.get_mut(p) {
+            *cell = value;
         }
     }
+
+    pub fn row(&self, y: i32) -> &[T] {
+        let start = (y * self.w) as usize;
+        &self.cells[start..start + self.w as usize]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on zero, an empty grid simply has no rows
+        self.cells.chunks(self.w.max(1) as usize)
+    }
+
+    /// Cells of column `x` from top to bottom, none if `x` is outside.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        let cells = match (0..self.w).contains(&x) {
+            true => &self.cells[x as usize..],
+            false => &[],
+        };
+        cells.iter().step_by(self.w.max(1) as usize)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.w).map(|x| self.column(x))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2i> {
+        let w = self.w;
+        (0..self.h).flat_map(move |y| (0..w).map(move |x| Vec2i::new(x, y)))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2i, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn map_cells<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            w: self.w,
+            h: self.h,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    pub fn read<P: Into<Vec2i>>(&self, p: P) -> Option<T> {
+        self.get(p).copied()
+    }
+
+    pub fn write<P: Into<Vec2i>>(&mut self, p: P, c: T) {
+        self.set(p, c);
+    }
+
+    /// Orthogonal neighbours of `p` that are inside the grid, with their cells.
+    pub fn neighbours_4_in_bounds(&self, p: Vec2i) -> impl Iterator<Item = (Vec2i, T)> + '_ {
+        p.neighbours_4()
+            .filter_map(|n| self.read(n).map(|cell| (n, cell)))
+    }
+
+    /// Like `neighbours_4_in_bounds`, keeping only cells that match.
+    pub fn neighbours_matching<'a>(
+        &'a self,
+        p: Vec2i,
+        predicate: impl Fn(T) -> bool + 'a,
+    ) -> impl Iterator<Item = (Vec2i, T)> + 'a {
+        self.neighbours_4_in_bounds(p)
+            .filter(move |&(_, cell)| predicate(cell))
+    }
+}
+
+impl<T: Copy> Grid<T> {
+    /// Orthogonal steps from the nearest of `sources` to every cell, moving
+    /// only through cells that `passable` accepts. Sources count as reached
+    /// even when they are not passable themselves.
+    pub fn distance_field(
+        &self,
+        sources: impl IntoIterator<Item = Vec2i>,
+        passable: impl Fn(T) -> bool,
+    ) -> Grid<Option<u32>> {
+        self.flood(sources, passable, |_, _| {})
+    }
+
+    /// Like `distance_field`, also recording how each cell was reached.
+    pub fn distance_field_with_parents(
+        &self,
+        sources: impl IntoIterator<Item = Vec2i>,
+        passable: impl Fn(T) -> bool,
+    ) -> DistanceField {
+        let mut parents = Grid::new(self.w, self.h, None);
+        let dist = self.flood(sources, passable, |p, back| parents[p] = Some(back));
+        DistanceField { dist, parents }
+    }
+
+    fn flood(
+        &self,
+        sources: impl IntoIterator<Item = Vec2i>,
+        passable: impl Fn(T) -> bool,
+        mut reached: impl FnMut(Vec2i, Dir4),
+    ) -> Grid<Option<u32>> {
+        let mut dist = Grid::new(self.w, self.h, None);
+        let mut queue = VecDeque::new();
+        for p in sources {
+            if self.inside(&p) && dist[p].is_none() {
+                dist[p] = Some(0);
+                queue.push_back(p);
+            }
+        }
+        while let Some(p) = queue.pop_front() {
+            let d = dist[p].unwrap();
+            for dir in Dir4::iter() {
+                let n = p + dir.to_vec2i();
+                if self.read(n).is_some_and(&passable) && dist[n].is_none() {
+                    dist[n] = Some(d + 1);
+                    reached(n, dir.opposite());
+                    queue.push_back(n);
+                }
+            }
+        }
+        dist
+    }
+}
+
+/// Result of `Grid::distance_field_with_parents`.
+#[derive(Clone)]
+pub struct DistanceField {
+    pub dist: Grid<Option<u32>>,
+    /// Direction back towards the nearest source, `None` on sources and
+    /// unreached cells.
+    pub parents: Grid<Option<Dir4>>,
+}
+
+impl DistanceField {
+    /// A shortest path from the nearest source to `target`, both included.
+    pub fn path_to(&self, target: Vec2i) -> Option<Vec<Vec2i>> {
+        self.dist.read(target)??;
+        let mut path = vec![target];
+        let mut p = target;
+        while let Some(back) = self.parents[p] {
+            p += back.to_vec2i();
+            path.push(p);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<T, P: Into<Vec2i>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: P) -> &T {
+        let p: Vec2i = p.into();
+        let i = self
+            .index_of(&p)
+            .unwrap_or_else(|| panic!("position {:?} outside grid", p));
+        &self.cells[i]
+    }
+}
+
+impl<T, P: Into<Vec2i>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, p: P) -> &mut T {
+        let p: Vec2i = p.into();
+        let i = self
+            .index_of(&p)
+            .unwrap_or_else(|| panic!("position {:?} outside grid", p));
+        &mut self.cells[i]
+    }
+}
+
+/// Number of cells in a `w` by `h` grid, panicking on negative sizes.
+fn cell_count(w: i32, h: i32) -> usize {
+    assert!(w >= 0 && h >= 0, "negative grid size {}x{}", w, h);
+    w as usize * h as usize
 }
 
 pub fn read_map(input: &str) -> Map {
-    let m: Vec<Vec<_>> = input.lines().map(|line| line.chars().collect()).collect();
-    let h = m.len() as i32;
-    let w = m[0].len() as i32;
-    Map { w, h, m }
+    Grid::from_rows(input.lines().map(|line| line.chars().collect()).collect())
 }
+
+/// Like `read_map`, but reports rows of different lengths instead of panicking.
+/// Blank lines are only allowed after the map.
+pub fn parse_map(input: &str) -> Result<Map, ParseError> {
+    parse::map_grid(&parse::lines(input).collect::<Vec<_>>())
+}
+
 pub type Point = Vec2i;
 
 pub fn read_at<P: Into<Point>>(map: &Map, p: P) -> Option<char> {
-    let point: Point = p.into();
-    if point.x < 0 || point.x >= map.w || point.y < 0 || point.y >= map.h {
-        None
-    } else {
-        Some(map.m[point.y as usize][point.x as usize])
+    map.read(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"0123
+4567
+89ab"#;
+
+    #[test]
+    fn grid_access_test() {
+        let mut map = read_map(INPUT);
+        assert_eq!((map.w, map.h), (4, 3));
+        assert_eq!(map.read((1, 2)), Some('9'));
+        assert_eq!(map.read((4, 0)), None);
+        assert_eq!(map.read((0, -1)), None);
+
+        map.write((3, 1), 'x');
+        assert_eq!(map[(3, 1)], 'x');
+        map.set((9, 9), 'y');
+        assert_eq!(map.cells().iter().filter(|&&c| c == 'y').count(), 0);
+
+        assert_eq!(map.row(1), &['4', '5', '6', 'x']);
+        assert_eq!(map.column(2).collect::<String>(), "26a");
+        assert_eq!(map.column(-1).count(), 0);
+        assert_eq!(map.column(4).count(), 0);
+        assert_eq!(map.columns().count(), 4);
+        assert_eq!(map.rows().count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "negative grid size")]
+    fn negative_size_test() {
+        Grid::new(-1, 2, 0);
+    }
+
+    #[test]
+    fn grid_map_cells_test() {
+        let map = read_map("012\n345");
+        let digits: Grid<u8> = map.map_cells(|c| c.to_digit(10).unwrap() as u8);
+        assert_eq!(digits[(2, 1)], 5);
+        assert_eq!(
+            digits.iter().map(|(p, &d)| (p.x + p.y * 3) as u8 - d).max(),
+            Some(0)
+        );
+        assert_eq!(digits.to_string(), "012\n345\n");
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let map = read_map(INPUT);
+        let corner: Vec<_> = map.neighbours_4_in_bounds(Vec2i::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![(Vec2i::new(1, 0), '1'), (Vec2i::new(0, 1), '4')]
+        );
+        assert_eq!(map.neighbours_4_in_bounds(Vec2i::new(1, 1)).count(), 4);
+        let letters: String = map
+            .neighbours_matching(Vec2i::new(3, 1), |c| c.is_ascii_alphabetic())
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(letters, "b");
+    }
+
+    #[test]
+    fn transform_test() {
+        let map = read_map("ab\ncd\nef");
+        assert_eq!(map.rotate_90().to_string(), "eca\nfdb\n");
+        assert_eq!(map.rotate_270().to_string(), "bdf\nace\n");
+        assert_eq!(map.rotate_180(), map.flip_h().flip_v());
+        assert_eq!(map.rotate_90().rotate_270(), map);
+        assert_eq!(map.transpose().to_string(), "ace\nbdf\n");
+        assert_eq!(map.flip_h().to_string(), "ba\ndc\nfe\n");
+
+        assert_eq!(
+            map.crop(Vec2i::new(1, 1), Vec2i::new(5, 5)).to_string(),
+            "d\nf\n"
+        );
+        assert_eq!(map.crop(Vec2i::new(3, 0), Vec2i::new(4, 1)).w, 0);
+        assert_eq!(map.tile(2, 1).to_string(), "abab\ncdcd\nefef\n");
+
+        let wide = map.scale_x(2, |c| if c == 'a' { "[]" } else { ".." });
+        assert_eq!(wide.row(0), &['[', ']', '.', '.']);
+    }
+
+    #[test]
+    fn find_test() {
+        let mut map = read_map("S.#\n.#E\n#..");
+        assert_eq!(map.find('#'), Some(Vec2i::new(2, 0)));
+        assert_eq!(map.find('x'), None);
+        assert_eq!(map.find_all('#').count(), 3);
+        assert_eq!(map.positions_by_char()[&'.'].len(), 4);
+
+        assert_eq!(map.take_marker('S', '.'), Ok(Vec2i::new(0, 0)));
+        assert_eq!(map.read((0, 0)), Some('.'));
+        assert_eq!(map.find_unique('E'), Ok(Vec2i::new(2, 1)));
+
+        let e = map.take_marker('S', '.').unwrap_err();
+        assert_eq!((e.line, e.message.as_str()), (1, "no 'S' on the map"));
+        let e = map.find_unique('#').unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert_eq!(e.text, ".#E");
+    }
+
+    #[test]
+    fn distance_field_test() {
+        let map = read_map("S.#\n.#.\n...");
+        let dist = map.distance_field([Vec2i::new(0, 0)], |c| c != '#');
+        assert_eq!(dist[(2, 1)], Some(5));
+        assert_eq!(dist[(2, 0)], None);
+
+        let sources = [Vec2i::new(0, 0), Vec2i::new(2, 1)];
+        let dist = map.distance_field(sources, |c| c != '#');
+        assert_eq!(dist[(1, 2)], Some(2));
+        assert_eq!(dist[(2, 2)], Some(1));
+
+        let field = map.distance_field_with_parents([Vec2i::new(0, 0)], |c| c != '#');
+        assert!(field.dist == map.distance_field([Vec2i::new(0, 0)], |c| c != '#'));
+        let path = field.path_to(Vec2i::new(2, 1)).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (Vec2i::new(0, 0), Vec2i::new(2, 1)));
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+        assert_eq!(field.path_to(Vec2i::new(2, 0)), None);
+    }
+
+    #[test]
+    fn parse_map_test() {
+        assert_eq!(parse_map(INPUT), Ok(read_map(INPUT)));
+        let e = parse_map("012\n34\n567").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(parse_map("012\n\n345\n").unwrap_err().line, 2);
+        assert_eq!(parse_map("\n012\n").unwrap_err().line, 1);
+        assert!(parse_map("012\n345\n\n").is_ok());
     }
 }
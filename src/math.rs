@@ -0,0 +1,130 @@
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, never negative. 0 when either argument is.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Extended Euclid: `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Euclidean remainder in `0..m`, `None` unless `m` is positive.
+pub fn rem(a: i64, m: i64) -> Option<i64> {
+    (m > 0).then(|| a.rem_euclid(m))
+}
+
+/// `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `m` is positive and `a` and
+/// `m` are coprime.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = egcd(rem(a, m)?, m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base^exp mod m`, by repeated squaring.
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> i64 {
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as i64
+}
+
+/// Chinese Remainder Theorem: the `x` that satisfies `x ≡ r (mod m)` for
+/// every `(r, m)`, as `(x, lcm of the moduli)` with `x` in `0..lcm`.
+/// The moduli need not be coprime. `None` when the congruences disagree,
+/// a modulus is not positive or the lcm does not fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| {
+        if m2 <= 0 {
+            return None;
+        }
+        // x = r1 + m1 * k, so m1 * k ≡ r2 - r1 (mod m2).
+        let (g, p, _) = egcd(m1, m2);
+        let (g, p, step) = (g as i128, p as i128, (m2 / g) as i128);
+        let diff = r2 as i128 - r1 as i128;
+        if diff % g != 0 {
+            return None;
+        }
+        let m = i64::try_from(m1 as i128 / g * m2 as i128).ok()?;
+        let k = diff / g % step * p % step;
+        let x = (r1 as i128 + m1 as i128 * k).rem_euclid(m as i128);
+        Some((x as i64, m))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_test() {
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        let (g, x, y) = egcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(rem(-7, 5), Some(3));
+        assert_eq!(rem(7, 0), None);
+        assert_eq!(rem(7, -5), None);
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(4, 10), None);
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 7), 6);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn crt_test() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 4), (0, 0)]), None);
+        assert_eq!(crt(&[(1, 4), (1, -3)]), None);
+
+        let big = (1 << 62) - 57;
+        assert_eq!(crt(&[(1, big), (2, 3)]), None);
+        let (x, m) = crt(&[(i64::MAX, big), (i64::MIN, 2)]).unwrap();
+        assert_eq!((x % big, x % 2, m), (i64::MAX % big, 0, 2 * big));
+
+        let (x, m) = crt(&[(12, 101), (70, 103)]).unwrap();
+        assert_eq!((x % 101, x % 103, m), (12, 70, 101 * 103));
+    }
+}
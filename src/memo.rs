@@ -0,0 +1,140 @@
+use std::{borrow::Borrow, collections::HashMap, fmt, hash::Hash};
+
+/// Cache for memoised recursion. The recursive function takes the memo as
+/// an argument and wraps its body in `get_or_compute`, which hands the memo
+/// back for the recursive calls, as in
+/// `memo.get_or_compute(&n, |memo| fib(memo, n - 1) + fib(memo, n - 2))`.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    limit: Option<usize>,
+    hits: u64,
+    misses: u64,
+}
+
+/// Cache counters, for profiling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoStats {
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} entries, {} hits, {} misses",
+            self.entries, self.hits, self.misses
+        )
+    }
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            limit: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops storing new results once `limit` entries are cached.
+    pub fn with_limit(limit: usize) -> Self {
+        Memo {
+            limit: Some(limit),
+            ..Self::default()
+        }
+    }
+
+    /// The cached value for `key`, or else the result of `compute`, which
+    /// gets the memo back for its recursive calls. `key` may be a borrowed
+    /// form of `K`, e.g. `&str` for `String` keys, and is only copied into
+    /// the cache on a miss.
+    pub fn get_or_compute<Q>(&mut self, key: &Q, compute: impl FnOnce(&mut Self) -> V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        if let Some(value) = self.cache.get(key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        if self.limit.is_none_or(|limit| self.cache.len() < limit) {
+            self.cache.insert(key.to_owned(), value.clone());
+        }
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Empties the cache. The counters keep running.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            entries: self.cache.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ways to write `s` as a sequence of the words "a", "ab" and "b".
+    fn splits(memo: &mut Memo<String, u64>, s: &str) -> u64 {
+        memo.get_or_compute(s, |memo| {
+            if s.is_empty() {
+                return 1;
+            }
+            ["a", "ab", "b"]
+                .iter()
+                .filter_map(|word| s.strip_prefix(word))
+                .map(|rest| splits(memo, rest))
+                .sum()
+        })
+    }
+
+    #[test]
+    fn memo_test() {
+        let mut memo = Memo::new();
+        assert_eq!(splits(&mut memo, "abab"), 4);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                entries: 5,
+                hits: 2,
+                misses: 5
+            }
+        );
+        assert_eq!(memo.stats().to_string(), "5 entries, 2 hits, 5 misses");
+
+        let mut limited = Memo::with_limit(2);
+        assert_eq!(splits(&mut limited, "abab"), 4);
+        assert_eq!(limited.len(), 2);
+        assert!(limited.stats().misses > 5);
+
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+}
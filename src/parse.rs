@@ -0,0 +1,236 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    error::{parse_at, ParseError},
+    map::{Grid, Map},
+};
+
+/// Integer types that `ints` can extract.
+pub trait Int: FromStr<Err: fmt::Display> {
+    /// Whether a `-` right before the digits belongs to the number.
+    const SIGNED: bool;
+}
+
+macro_rules! impl_int {
+    ($signed:expr; $($t:ty),*) => {
+        $(impl Int for $t {
+            const SIGNED: bool = $signed;
+        })*
+    };
+}
+
+impl_int!(true; i8, i16, i32, i64, i128, isize);
+impl_int!(false; u8, u16, u32, u64, u128, usize);
+
+/// Byte ranges of the integers in `text`, signed ones include a leading `-`.
+fn int_spans(text: &str, signed: bool) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+        let start = if signed && i > 0 && bytes[i - 1] == b'-' {
+            i - 1
+        } else {
+            i
+        };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        Some(&text[start..i])
+    })
+}
+
+/// One line of input with its zero-based index, for error reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// All integers in the line, ignoring whatever is between them.
+    pub fn ints<T: Int>(&self) -> Result<Vec<T>, ParseError> {
+        int_spans(self.text, T::SIGNED)
+            .map(|part| parse_at(self.index, self.text, part))
+            .collect()
+    }
+
+    /// Exactly `N` integers, e.g. `let [x, y] = line.ints_exact()?`.
+    pub fn ints_exact<T: Int, const N: usize>(&self) -> Result<[T; N], ParseError> {
+        let ints = self.ints()?;
+        let found = ints.len();
+        ints.try_into()
+            .map_err(|_| self.error(format!("expected {} numbers, found {}", N, found)))
+    }
+
+    /// Splits around the first `separator`, trimming both sides.
+    pub fn key_value(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(separator)
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .ok_or_else(|| self.error(format!("expected '{}'", separator)))
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::line(self.index, self.text, message)
+    }
+
+    /// Error about `part`, which must be a slice of this line.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.index, self.text, part, message)
+    }
+}
+
+/// Non-empty lines of `input`. Lines may end with `\n` or `\r\n`.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Groups of lines separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![];
+    let mut current = vec![];
+    for (index, text) in input.lines().enumerate() {
+        if text.trim().is_empty() {
+            if !current.is_empty() {
+                sections.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(Line { index, text });
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// Like `sections`, but requires exactly `N` of them.
+pub fn sections_exact<const N: usize>(input: &str) -> Result<[Vec<Line<'_>>; N], ParseError> {
+    let sections = sections(input);
+    let found = sections.len();
+    sections.try_into().map_err(|_| {
+        let (index, text) = input.lines().enumerate().last().unwrap_or((0, ""));
+        ParseError::line(
+            index,
+            text,
+            format!(
+                "expected {} sections separated by blank lines, found {}",
+                N, found
+            ),
+        )
+    })
+}
+
+/// All integers in `input`, with errors pointing at the right line.
+pub fn ints<T: Int>(input: &str) -> Result<Vec<T>, ParseError> {
+    let mut all = vec![];
+    for line in lines(input) {
+        all.extend(line.ints()?);
+    }
+    Ok(all)
+}
+
+/// Character grid from consecutive lines of equal length.
+pub fn grid(lines: &[Line]) -> Result<Map, ParseError> {
+    let Some(first) = lines.first() else {
+        return Ok(Grid::from_rows(vec![]));
+    };
+    let w = first.text.trim_end().chars().count();
+    let mut rows = Vec::with_capacity(lines.len());
+    for (y, line) in lines.iter().enumerate() {
+        if line.index != first.index + y {
+            return Err(ParseError::line(
+                first.index + y,
+                "",
+                "blank line inside the map",
+            ));
+        }
+        let text = line.text.trim_end();
+        if text.chars().count() != w {
+            let column = text.char_indices().nth(w).map_or(text.len(), |(i, _)| i);
+            return Err(ParseError::new(
+                line.index,
+                column,
+                line.text,
+                format!("expected {} columns like the first row", w),
+            ));
+        }
+        rows.push(text.chars().collect());
+    }
+    Ok(Grid::from_rows(rows))
+}
+
+/// Like `grid`, but the lines must start at the top of the input, so that
+/// row y of the map is line y as `Map::error_at` assumes.
+pub fn map_grid(lines: &[Line]) -> Result<Map, ParseError> {
+    match lines.first() {
+        Some(first) if first.index > 0 => Err(ParseError::line(
+            0,
+            "",
+            "expected the map on the first line",
+        )),
+        _ => grid(lines),
+    }
+}
+
+/// A map followed by a blank line and more input, like robot instructions.
+pub fn grid_and_rest(input: &str) -> Result<(Map, Vec<Line<'_>>), ParseError> {
+    let mut sections = sections(input).into_iter();
+    let map = map_grid(&sections.next().unwrap_or_default())?;
+    Ok((map, sections.flatten().collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_test() {
+        assert_eq!(ints::<i32>("p=0,4 v=3,-3").unwrap(), vec![0, 4, 3, -3]);
+        assert_eq!(ints::<u32>("kh-12, x+7").unwrap(), vec![12, 7]);
+        assert_eq!(ints::<i64>("1\n\n-2 3\n").unwrap(), vec![1, -2, 3]);
+
+        let e = ints::<u8>("1 2\n3 256").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+
+        let line = Line {
+            index: 4,
+            text: "Button A: X+94, Y+34",
+        };
+        assert_eq!(line.ints_exact::<i64, 2>().unwrap(), [94, 34]);
+        assert_eq!(line.ints_exact::<i64, 3>().unwrap_err().line, 5);
+        assert_eq!(line.key_value(":").unwrap(), ("Button A", "X+94, Y+34"));
+    }
+
+    #[test]
+    fn sections_test() {
+        let input = "a\r\nb\r\n\r\n  \r\nc\r\n";
+        let sections = sections(input);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(
+            sections[1][0],
+            Line {
+                index: 4,
+                text: "c"
+            }
+        );
+        assert!(sections_exact::<2>(input).is_ok());
+        assert_eq!(sections_exact::<3>(input).unwrap_err().line, 5);
+
+        let (map, rest) = grid_and_rest("#.@\r\n#..\r\n\r\n<>\r\nv^\r\n").unwrap();
+        assert_eq!((map.w, map.h), (3, 2));
+        assert_eq!(rest.iter().map(|l| l.text).collect::<String>(), "<>v^");
+        assert_eq!(grid_and_rest("##\n#\n").unwrap_err().line, 2);
+        assert_eq!(grid_and_rest("\n##\n\n<\n").unwrap_err().line, 1);
+    }
+}
@@ -0,0 +1,244 @@
+use std::slice;
+
+use crate::{dir::Dir4, map::Grid, vec2::Vec2i, vecn::Vector};
+
+/// Which neighbours join equal cells into one region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Cells that share an edge.
+    Four,
+    /// Cells that share an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    pub fn neighbours(self, p: Vec2i) -> impl Iterator<Item = Vec2i> {
+        p.neighbours_8()
+            .filter(move |n| self == Connectivity::Eight || n.manhattan(p) == 1)
+    }
+
+    /// Connectivity of the background around a region, so that holes
+    /// neither leak through nor get split by diagonal gaps.
+    fn dual(self) -> Self {
+        match self {
+            Connectivity::Four => Connectivity::Eight,
+            Connectivity::Eight => Connectivity::Four,
+        }
+    }
+}
+
+/// Measurements of one connected region.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Topmost, then leftmost cell.
+    pub start: Vec2i,
+    pub area: usize,
+    /// Cell edges that face another region or the outside.
+    pub perimeter: usize,
+    /// Straight runs of perimeter edges.
+    pub sides: usize,
+    /// Convex plus concave corners, which always equals `sides`.
+    pub corners: usize,
+    /// Inclusive (min, max) corners.
+    pub bounds: (Vec2i, Vec2i),
+}
+
+impl Region {
+    /// Background areas fully enclosed by the region. Computed on demand,
+    /// as it floods the region's bounding box.
+    pub fn holes(&self, regions: &Regions) -> usize {
+        let id = regions.labels[self.start];
+        count_holes(
+            &regions.labels,
+            id,
+            self.bounds,
+            regions.connectivity.dual(),
+        )
+    }
+}
+
+/// Connected components of equal cells. `labels` holds the region index
+/// of every cell.
+#[derive(Clone)]
+pub struct Regions {
+    pub labels: Grid<u32>,
+    regions: Vec<Region>,
+    connectivity: Connectivity,
+}
+
+impl Regions {
+    pub fn label<T: Copy + PartialEq>(grid: &Grid<T>, connectivity: Connectivity) -> Self {
+        const UNLABELLED: u32 = u32::MAX;
+
+        let mut labels = Grid::new(grid.w, grid.h, UNLABELLED);
+        let mut starts = Vec::new();
+        for start in grid.positions() {
+            if labels[start] != UNLABELLED {
+                continue;
+            }
+            let id = starts.len() as u32;
+            let value = grid[start];
+            starts.push(start);
+            labels[start] = id;
+            let mut stack = vec![start];
+            while let Some(p) = stack.pop() {
+                for n in connectivity.neighbours(p) {
+                    if grid.read(n) == Some(value) && labels[n] == UNLABELLED {
+                        labels[n] = id;
+                        stack.push(n);
+                    }
+                }
+            }
+        }
+
+        let mut regions: Vec<Region> = starts
+            .into_iter()
+            .map(|start| Region {
+                start,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                corners: 0,
+                bounds: (start, start),
+            })
+            .collect();
+
+        for (p, &id) in labels.iter() {
+            let inside = |q: Vec2i| labels.read(q) == Some(id);
+            let region = &mut regions[id as usize];
+            region.area += 1;
+            region.bounds = (
+                region.bounds.0.min_components(p),
+                region.bounds.1.max_components(p),
+            );
+            for dir in Dir4::iter() {
+                let out = dir.to_vec2i();
+                let along = dir.turn_right().to_vec2i();
+                if !inside(p + out) {
+                    region.perimeter += 1;
+                    // A side starts where the previous cell along it has no such edge.
+                    if !inside(p - along) || inside(p - along + out) {
+                        region.sides += 1;
+                    }
+                }
+                let (a, b) = (inside(p + out), inside(p + along));
+                if (!a && !b) || (a && b && !inside(p + out + along)) {
+                    region.corners += 1;
+                }
+            }
+        }
+
+        Regions {
+            labels,
+            regions,
+            connectivity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Region> {
+        self.regions.iter()
+    }
+
+    /// The region that `p` belongs to.
+    pub fn region_at(&self, p: Vec2i) -> Option<&Region> {
+        self.labels.read(p).map(|id| &self.regions[id as usize])
+    }
+}
+
+impl<'a> IntoIterator for &'a Regions {
+    type Item = &'a Region;
+    type IntoIter = slice::Iter<'a, Region>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Counts the background components around region `id` that cannot reach
+/// the outside of its bounding box.
+fn count_holes(
+    labels: &Grid<u32>,
+    id: u32,
+    (min, max): (Vec2i, Vec2i),
+    background: Connectivity,
+) -> usize {
+    // The box gets a one cell border, so that all outside cells are connected.
+    let origin = min - (1, 1);
+    let size = max - min + (3, 3);
+    let mut open = Grid::from_fn(size.x, size.y, |p| labels.read(p + origin) != Some(id));
+
+    let fill = |open: &mut Grid<bool>, start: Vec2i| {
+        open[start] = false;
+        let mut stack = vec![start];
+        while let Some(p) = stack.pop() {
+            for n in background.neighbours(p) {
+                if open.read(n) == Some(true) {
+                    open[n] = false;
+                    stack.push(n);
+                }
+            }
+        }
+    };
+
+    fill(&mut open, Vec2i::new(0, 0));
+    let mut holes = 0;
+    for p in open.positions() {
+        if open[p] {
+            fill(&mut open, p);
+            holes += 1;
+        }
+    }
+    holes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::read_map;
+
+    #[test]
+    fn regions_test() {
+        let map = read_map("AAAA\nABBA\nAAAA");
+        let regions = Regions::label(&map, Connectivity::Four);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions.labels.to_string(), "0000\n0110\n0000\n");
+
+        let a = regions.region_at(Vec2i::new(3, 2)).unwrap();
+        assert_eq!((a.area, a.perimeter, a.sides, a.corners), (10, 20, 8, 8));
+        assert_eq!(a.bounds, (Vec2i::new(0, 0), Vec2i::new(3, 2)));
+        assert_eq!(a.holes(&regions), 1);
+
+        let b = regions.region_at(Vec2i::new(1, 1)).unwrap();
+        assert_eq!(b.start, Vec2i::new(1, 1));
+        assert_eq!((b.area, b.perimeter, b.sides), (2, 6, 4));
+        assert_eq!(b.holes(&regions), 0);
+
+        let two_holes = Regions::label(&read_map("AAAAA\nABACA\nAAAAA"), Connectivity::Four);
+        assert_eq!(two_holes.iter().next().unwrap().holes(&two_holes), 2);
+    }
+
+    #[test]
+    fn connectivity_test() {
+        let map = read_map(".X.\nX.X\n.X.");
+        assert_eq!(Regions::label(&map, Connectivity::Four).len(), 9);
+
+        let regions = Regions::label(&map, Connectivity::Eight);
+        assert_eq!(regions.len(), 2);
+        let ring = regions.region_at(Vec2i::new(1, 0)).unwrap();
+        assert_eq!((ring.area, ring.perimeter, ring.sides), (4, 16, 16));
+        assert_eq!(ring.corners, ring.sides);
+        assert_eq!(ring.holes(&regions), 1);
+        assert_eq!(
+            regions.region_at(Vec2i::new(0, 0)).unwrap().holes(&regions),
+            0
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    collections::HashMap,
+    env, fmt,
+    io::{self, IsTerminal},
+};
+
+use crate::{dir::Dir4, map::Map, vec2::Vec2i, vecn::Vector};
+
+/// The basic ANSI terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    fn ansi_code(self) -> u8 {
+        30 + self as u8
+    }
+}
+
+/// Whether stdout is a terminal that wants colours. Honours `NO_COLOR`.
+pub fn colour_enabled() -> bool {
+    colour_enabled_for(&io::stdout())
+}
+
+/// Like `colour_enabled`, for output that goes to `stream` instead, e.g.
+/// `io::stderr()` for maps written through the log.
+pub fn colour_enabled_for(stream: &impl IsTerminal) -> bool {
+    stream.is_terminal() && env::var_os("NO_COLOR").is_none()
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Overlay {
+    glyph: Option<char>,
+    colour: Option<Colour>,
+}
+
+/// Draws a `Map` for the terminal, with overlays on top of the map's own
+/// characters. Later overlays win over earlier ones.
+pub struct Renderer<'a> {
+    map: &'a Map,
+    palette: HashMap<char, Colour>,
+    overlays: HashMap<Vec2i, Overlay>,
+    viewport: Option<(Vec2i, Vec2i)>,
+    colour: bool,
+}
+
+impl<'a> Renderer<'a> {
+    /// Colours are on when stdout is a terminal, see `colour_enabled`.
+    pub fn new(map: &'a Map) -> Self {
+        Renderer {
+            map,
+            palette: HashMap::new(),
+            overlays: HashMap::new(),
+            viewport: None,
+            colour: colour_enabled(),
+        }
+    }
+
+    /// Draws every `c` on the map in `colour`.
+    pub fn palette(mut self, c: char, colour: Colour) -> Self {
+        self.palette.insert(c, colour);
+        self
+    }
+
+    /// Recolours `points`, keeping whatever is drawn there.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Vec2i>, colour: Colour) -> Self {
+        for p in points {
+            self.overlays.entry(p).or_default().colour = Some(colour);
+        }
+        self
+    }
+
+    /// Draws each step of `path` as an arrow towards the next position.
+    /// The last position keeps its glyph; steps that are not a single
+    /// orthogonal move are drawn as `*`.
+    pub fn path(mut self, path: &[Vec2i], colour: Colour) -> Self {
+        for (i, &p) in path.iter().enumerate() {
+            let overlay = self.overlays.entry(p).or_default();
+            overlay.colour = Some(colour);
+            if let Some(&next) = path.get(i + 1) {
+                let glyph = Dir4::from_vec2i(&(next - p)).map_or('*', Dir4::to_arrow_char);
+                overlay.glyph = Some(glyph);
+            }
+        }
+        self
+    }
+
+    /// Writes `text` rightwards from `p`, e.g. a number next to a node.
+    pub fn label(mut self, p: Vec2i, text: impl fmt::Display, colour: Colour) -> Self {
+        for (i, c) in text.to_string().chars().enumerate() {
+            let overlay = self
+                .overlays
+                .entry(p + Vec2i::new(i as i32, 0))
+                .or_default();
+            overlay.glyph = Some(c);
+            overlay.colour = Some(colour);
+        }
+        self
+    }
+
+    /// Only draws the inclusive box `min..=max`, for maps too large to read.
+    pub fn viewport(mut self, min: Vec2i, max: Vec2i) -> Self {
+        self.viewport = Some((min, max));
+        self
+    }
+
+    /// Turns ANSI colours on or off, overriding the terminal check.
+    pub fn colour(mut self, enabled: bool) -> Self {
+        self.colour = enabled;
+        self
+    }
+}
+
+impl fmt::Display for Renderer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut min = Vec2i::new(0, 0);
+        let mut max = Vec2i::new(self.map.w - 1, self.map.h - 1);
+        if let Some((view_min, view_max)) = self.viewport {
+            min = min.max_components(view_min);
+            max = max.min_components(view_max);
+        }
+
+        for y in min.y..=max.y {
+            let mut current = None;
+            for x in min.x..=max.x {
+                let p = Vec2i::new(x, y);
+                let c = self.map[p];
+                let overlay = self.overlays.get(&p).copied().unwrap_or_default();
+                let glyph = overlay.glyph.unwrap_or(c);
+                if self.colour {
+                    let colour = overlay.colour.or_else(|| self.palette.get(&c).copied());
+                    if colour != current {
+                        match colour {
+                            Some(colour) => write!(f, "\x1b[{}m", colour.ansi_code())?,
+                            None => write!(f, "\x1b[0m")?,
+                        }
+                        current = colour;
+                    }
+                }
+                write!(f, "{}", glyph)?;
+            }
+            if current.is_some() {
+                write!(f, "\x1b[0m")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::read_map;
+
+    #[test]
+    fn render_test() {
+        let map = read_map("#####\n#...#\n#.#.#\n#####");
+        let path = [(1, 2), (1, 1), (2, 1), (3, 1), (3, 2)].map(Vec2i::from);
+        let view = Renderer::new(&map)
+            .colour(false)
+            .palette('#', Colour::Blue)
+            .highlight([Vec2i::new(2, 2)], Colour::Red)
+            .path(&path, Colour::Yellow);
+        assert_eq!(view.to_string(), "#####\n#>>v#\n#^#.#\n#####\n");
+
+        let view = view.label(Vec2i::new(2, 2), 42, Colour::Green);
+        assert_eq!(view.to_string(), "#####\n#>>v#\n#^42#\n#####\n");
+
+        let view = view.viewport(Vec2i::new(2, -5), Vec2i::new(9, 1));
+        assert_eq!(view.to_string(), "###\n>v#\n");
+    }
+
+    #[test]
+    fn colour_test() {
+        let map = read_map("#.#");
+        let view = Renderer::new(&map)
+            .colour(true)
+            .palette('#', Colour::Blue)
+            .highlight([Vec2i::new(2, 0)], Colour::Red);
+        assert_eq!(view.to_string(), "\x1b[34m#\x1b[0m.\x1b[31m#\x1b[0m\n");
+    }
+}
@@ -0,0 +1,449 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// A graph search over implicit states.
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn start(&self) -> Self::State;
+
+    /// Successor states together with the cost of moving there.
+    fn neighbours(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, usize)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// Lower bound of the remaining cost to a goal, used by `astar`.
+    /// Must never overestimate for the result to be optimal.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+/// The cost of a search result together with one path from start to goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    pub states: Vec<S>,
+}
+
+/// States seen by a search, numbered in discovery order.
+struct Explored<S> {
+    states: Vec<S>,
+    ids: HashMap<S, usize>,
+    dist: Vec<usize>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl<S: Clone + Eq + Hash> Explored<S> {
+    fn new() -> Self {
+        Explored {
+            states: Vec::new(),
+            ids: HashMap::new(),
+            dist: Vec::new(),
+            predecessors: Vec::new(),
+        }
+    }
+
+    fn id(&mut self, state: &S) -> usize {
+        if let Some(&id) = self.ids.get(state) {
+            return id;
+        }
+        let id = self.states.len();
+        self.states.push(state.clone());
+        self.ids.insert(state.clone(), id);
+        self.dist.push(usize::MAX);
+        self.predecessors.push(Vec::new());
+        id
+    }
+
+    /// Follows the first predecessor of every state back to the start.
+    fn path_to(&self, goal: usize) -> Vec<S> {
+        let mut path = vec![self.states[goal].clone()];
+        let mut id = goal;
+        while let Some(&prev) = self.predecessors[id].first() {
+            path.push(self.states[prev].clone());
+            id = prev;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search where every move counts as one step, edge costs
+/// from the problem are ignored.
+pub fn bfs<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    let mut explored = Explored::new();
+    let mut queue = VecDeque::new();
+
+    let start = explored.id(&problem.start());
+    explored.dist[start] = 0;
+    queue.push_back(start);
+
+    while let Some(id) = queue.pop_front() {
+        let state = explored.states[id].clone();
+        if problem.is_goal(&state) {
+            return Some(Path {
+                cost: explored.dist[id],
+                states: explored.path_to(id),
+            });
+        }
+
+        for (next, _) in problem.neighbours(&state) {
+            let next_id = explored.id(&next);
+            if explored.dist[next_id] == usize::MAX {
+                explored.dist[next_id] = explored.dist[id] + 1;
+                explored.predecessors[next_id].push(id);
+                queue.push_back(next_id);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    best_first(problem, |_| 0)
+}
+
+/// A* search guided by `SearchProblem::heuristic`.
+pub fn astar<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    best_first(problem, |state| problem.heuristic(state))
+}
+
+fn best_first<P: SearchProblem, H: Fn(&P::State) -> usize>(
+    problem: &P,
+    heuristic: H,
+) -> Option<Path<P::State>> {
+    let mut explored = Explored::new();
+    let mut queue = BinaryHeap::new();
+
+    let start_state = problem.start();
+    let start = explored.id(&start_state);
+    explored.dist[start] = 0;
+    queue.push(Reverse((heuristic(&start_state), 0, start)));
+
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        if cost > explored.dist[id] {
+            continue;
+        }
+
+        let state = explored.states[id].clone();
+        if problem.is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: explored.path_to(id),
+            });
+        }
+
+        for (next, step_cost) in problem.neighbours(&state) {
+            let next_id = explored.id(&next);
+            let next_cost = cost + step_cost;
+            if next_cost < explored.dist[next_id] {
+                explored.dist[next_id] = next_cost;
+                explored.predecessors[next_id] = vec![id];
+                queue.push(Reverse((next_cost + heuristic(&next), next_cost, next_id)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every optimal way to reach a goal, as found by `dijkstra_all`.
+pub struct ShortestPaths<S> {
+    pub cost: usize,
+    /// Goal states reached at the optimal cost.
+    pub goals: Vec<S>,
+    explored: Explored<S>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// Cost of the cheapest known route from the start to `state`.
+    pub fn dist(&self, state: &S) -> Option<usize> {
+        self.explored
+            .ids
+            .get(state)
+            .map(|&id| self.explored.dist[id])
+            .filter(|&d| d != usize::MAX)
+    }
+
+    /// States from which `state` is reached on a cheapest route.
+    pub fn predecessors<'a>(&'a self, state: &S) -> impl Iterator<Item = &'a S> + 'a {
+        let ids = match self.explored.ids.get(state) {
+            Some(&id) => &self.explored.predecessors[id][..],
+            None => &[],
+        };
+        ids.iter().map(|&id| &self.explored.states[id])
+    }
+
+    /// One optimal path to the first goal.
+    pub fn path(&self) -> Path<S> {
+        Path {
+            cost: self.cost,
+            states: self.explored.path_to(self.explored.ids[&self.goals[0]]),
+        }
+    }
+
+    fn goal_ids(&self) -> Vec<usize> {
+        self.goals
+            .iter()
+            .map(|goal| self.explored.ids[goal])
+            .collect()
+    }
+
+    /// The states and moves that lie on at least one optimal path.
+    pub fn optimal_subgraph(&self) -> OptimalSubgraph<S> {
+        let mut seen = vec![false; self.explored.states.len()];
+        let mut edges = HashSet::new();
+        let mut stack = self.goal_ids();
+        for &id in &stack {
+            seen[id] = true;
+        }
+
+        while let Some(id) = stack.pop() {
+            for &prev in &self.explored.predecessors[id] {
+                edges.insert((
+                    self.explored.states[prev].clone(),
+                    self.explored.states[id].clone(),
+                ));
+                if !seen[prev] {
+                    seen[prev] = true;
+                    stack.push(prev);
+                }
+            }
+        }
+
+        let states = seen
+            .iter()
+            .zip(&self.explored.states)
+            .filter(|(&seen, _)| seen)
+            .map(|(_, state)| state.clone())
+            .collect();
+
+        OptimalSubgraph {
+            states,
+            edges,
+            path_count: self.count_paths(),
+        }
+    }
+
+    /// Number of distinct optimal paths, `None` if it overflows a u128.
+    pub fn count_paths(&self) -> Option<u128> {
+        // counts[id] is None until computed, then Some(None) on overflow
+        let mut counts: Vec<Option<Option<u128>>> = vec![None; self.explored.states.len()];
+        let mut total = Some(0u128);
+
+        for goal in self.goal_ids() {
+            let mut stack = vec![goal];
+            while let Some(&id) = stack.last() {
+                if counts[id].is_some() {
+                    stack.pop();
+                    continue;
+                }
+
+                let preds = &self.explored.predecessors[id];
+                let pending: Vec<_> = preds
+                    .iter()
+                    .copied()
+                    .filter(|&prev| counts[prev].is_none())
+                    .collect();
+                if !pending.is_empty() {
+                    stack.extend(pending);
+                    continue;
+                }
+
+                let count = if preds.is_empty() {
+                    Some(1)
+                } else {
+                    preds.iter().try_fold(0u128, |acc, &prev| {
+                        counts[prev].flatten().and_then(|c| acc.checked_add(c))
+                    })
+                };
+                counts[id] = Some(count);
+                stack.pop();
+            }
+
+            total = total.and_then(|t| counts[goal].flatten().and_then(|c| t.checked_add(c)));
+        }
+
+        total
+    }
+}
+
+/// The part of a state graph covered by optimal paths.
+#[derive(Debug, Clone)]
+pub struct OptimalSubgraph<S> {
+    pub states: HashSet<S>,
+    /// Moves `(from, to)` taken by at least one optimal path.
+    pub edges: HashSet<(S, S)>,
+    /// Number of distinct optimal paths, `None` if it overflows a u128.
+    pub path_count: Option<u128>,
+}
+
+/// Finds every state and move on a minimum-cost path to a goal.
+/// Edge costs must be positive for the path count to be well defined.
+pub fn all_shortest_paths<P: SearchProblem>(problem: &P) -> Option<OptimalSubgraph<P::State>> {
+    dijkstra_all(problem).map(|paths| paths.optimal_subgraph())
+}
+
+/// Dijkstra search that keeps all equally cheap predecessors of every state,
+/// so the result describes every optimal path rather than just one.
+pub fn dijkstra_all<P: SearchProblem>(problem: &P) -> Option<ShortestPaths<P::State>> {
+    let mut explored = Explored::new();
+    let mut queue = BinaryHeap::new();
+    let mut best: Option<usize> = None;
+    let mut goals = Vec::new();
+
+    let start = explored.id(&problem.start());
+    explored.dist[start] = 0;
+    queue.push(Reverse((0, start)));
+
+    while let Some(Reverse((cost, id))) = queue.pop() {
+        if cost > explored.dist[id] {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        let state = explored.states[id].clone();
+        if problem.is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+
+        for (next, step_cost) in problem.neighbours(&state) {
+            let next_id = explored.id(&next);
+            let next_cost = cost + step_cost;
+            if next_cost < explored.dist[next_id] {
+                explored.dist[next_id] = next_cost;
+                explored.predecessors[next_id] = vec![id];
+                queue.push(Reverse((next_cost, next_id)));
+            } else if next_cost == explored.dist[next_id]
+                && !explored.predecessors[next_id].contains(&id)
+            {
+                explored.predecessors[next_id].push(id);
+            }
+        }
+    }
+
+    best.map(|cost| ShortestPaths {
+        cost,
+        goals,
+        explored,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Walk along the number line, +1 costs 1 and *2 costs 2.
+    struct Line {
+        target: i64,
+    }
+
+    impl SearchProblem for Line {
+        type State = i64;
+
+        fn start(&self) -> i64 {
+            1
+        }
+
+        fn neighbours(&self, &n: &i64) -> impl IntoIterator<Item = (i64, usize)> {
+            [(n + 1, 1), (n * 2, 2)]
+                .into_iter()
+                .filter(|&(m, _)| m <= self.target)
+        }
+
+        fn is_goal(&self, &n: &i64) -> bool {
+            n == self.target
+        }
+
+        fn heuristic(&self, &n: &i64) -> usize {
+            // every move at most doubles n and costs at least 1
+            if n >= self.target {
+                0
+            } else {
+                ((self.target / n) as f64).log2() as usize
+            }
+        }
+    }
+
+    #[test]
+    fn search_test() {
+        let problem = Line { target: 20 };
+
+        let path = bfs(&problem).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.len(), 6);
+
+        let path = dijkstra(&problem).unwrap();
+        assert_eq!(path.cost, 8);
+        assert_eq!(path.states, vec![1, 2, 4, 5, 10, 20]);
+
+        assert_eq!(astar(&problem).unwrap().cost, 8);
+        assert!(bfs(&Line { target: 0 }).is_none());
+    }
+
+    #[test]
+    fn dijkstra_all_test() {
+        // 1 -> 2 -> 3 -> 4 costs 3 and so does 1 -> 2 -> 4
+        let paths = dijkstra_all(&Line { target: 4 }).unwrap();
+        assert_eq!(paths.cost, 3);
+        assert_eq!(paths.goals, vec![4]);
+        let mut preds: Vec<_> = paths.predecessors(&4).copied().collect();
+        preds.sort();
+        assert_eq!(preds, vec![2, 3]);
+        assert_eq!(paths.dist(&3), Some(2));
+        assert_eq!(paths.path().states.len(), 3);
+    }
+
+    #[test]
+    fn all_shortest_paths_test() {
+        let subgraph = all_shortest_paths(&Line { target: 4 }).unwrap();
+        assert_eq!(subgraph.states, HashSet::from([1, 2, 3, 4]));
+        assert_eq!(
+            subgraph.edges,
+            HashSet::from([(1, 2), (2, 3), (3, 4), (2, 4)])
+        );
+        assert_eq!(subgraph.path_count, Some(2));
+    }
+
+    /// A ladder of diamonds, every rung doubles the number of optimal paths.
+    struct Diamonds {
+        rungs: u32,
+    }
+
+    impl SearchProblem for Diamonds {
+        type State = (u32, bool);
+
+        fn start(&self) -> (u32, bool) {
+            (0, false)
+        }
+
+        fn neighbours(
+            &self,
+            &(rung, _): &(u32, bool),
+        ) -> impl IntoIterator<Item = ((u32, bool), usize)> {
+            [((rung + 1, false), 1), ((rung + 1, true), 1)]
+        }
+
+        fn is_goal(&self, &(rung, _): &(u32, bool)) -> bool {
+            rung == self.rungs
+        }
+    }
+
+    #[test]
+    fn path_count_overflow_test() {
+        let paths = dijkstra_all(&Diamonds { rungs: 100 }).unwrap();
+        assert_eq!(paths.count_paths(), Some(1 << 100));
+
+        let paths = dijkstra_all(&Diamonds { rungs: 128 }).unwrap();
+        assert_eq!(paths.count_paths(), None);
+    }
+}
@@ -0,0 +1,227 @@
+use std::{cell::Cell, collections::HashMap, fmt, ops::Index};
+
+use crate::{
+    map::Grid,
+    vec2::Vec2i,
+    vecn::{bounding_box, Vector},
+};
+
+/// Unbounded grid that only stores occupied cells, for simulations where
+/// most of the area is empty. The bounding box follows the stored cells.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2i, T>,
+    /// Inclusive (min, max) corners around all cells, `None` when empty.
+    /// Removals only mark the box as loose; `bounds` tightens it on demand,
+    /// so that moving cells around stays cheap.
+    bounds: Cell<Option<(Vec2i, Vec2i)>>,
+    tight: Cell<bool>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: Cell::new(None),
+            tight: Cell::new(true),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for SparseGrid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: Eq> Eq for SparseGrid<T> {}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes the cells of `grid` that `keep` accepts, at the same positions.
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        grid.iter()
+            .filter(|(_, cell)| keep(cell))
+            .map(|(p, cell)| (p, cell.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Inclusive (min, max) corners around all cells.
+    pub fn bounds(&self) -> Option<(Vec2i, Vec2i)> {
+        if !self.tight.replace(true) {
+            self.bounds.set(bounding_box(self.cells.keys().copied()));
+        }
+        self.bounds.get()
+    }
+
+    pub fn contains(&self, p: Vec2i) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn get(&self, p: Vec2i) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Vec2i) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// Stores `value` at `p`, returning what was there before.
+    pub fn insert(&mut self, p: Vec2i, value: T) -> Option<T> {
+        self.bounds.set(Some(match self.bounds.get() {
+            None => (p, p),
+            Some((min, max)) => (min.min_components(p), max.max_components(p)),
+        }));
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Vec2i) -> Option<T> {
+        let removed = self.cells.remove(&p)?;
+        if let Some((min, max)) = self.bounds.get() {
+            if p.x == min.x || p.y == min.y || p.x == max.x || p.y == max.y {
+                self.tight.set(false);
+            }
+        }
+        Some(removed)
+    }
+
+    /// Moves the cell at `from` to `to`, replacing whatever was there.
+    /// Returns false, changing nothing, when `from` is empty.
+    pub fn move_cell(&mut self, from: Vec2i, to: Vec2i) -> bool {
+        match self.remove(from) {
+            Some(value) => {
+                self.insert(to, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// All cells in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2i, &T)> {
+        self.cells.iter().map(|(&p, cell)| (p, cell))
+    }
+
+    /// Cells inside the inclusive box `min..=max`, in row-major order.
+    pub fn region(&self, min: Vec2i, max: Vec2i) -> impl Iterator<Item = (Vec2i, &T)> {
+        let mut cells: Vec<_> = self
+            .iter()
+            .filter(|(p, _)| p.inside_box(min, max))
+            .collect();
+        cells.sort_by_key(|&(p, _)| (p.y, p.x));
+        cells.into_iter()
+    }
+
+    /// Dense copy of the bounding box, together with the box's min corner.
+    /// Cell `p` of the grid holds cell `min + p` of this one.
+    pub fn to_grid(&self, fill: T) -> (Vec2i, Grid<T>)
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return (Vec2i::new(0, 0), Grid::from_rows(vec![]));
+        };
+        let size = max - min + (1, 1);
+        let grid = Grid::from_fn(size.x, size.y, |p| {
+            self.get(p + min).cloned().unwrap_or_else(|| fill.clone())
+        });
+        (min, grid)
+    }
+}
+
+impl<T: fmt::Display> SparseGrid<T> {
+    /// Draws the bounding box, with `fill` for empty cells.
+    pub fn render(&self, fill: char) -> String {
+        let mut s = String::new();
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    match self.get(Vec2i::new(x, y)) {
+                        Some(cell) => s += &cell.to_string(),
+                        None => s.push(fill),
+                    }
+                }
+                s.push('\n');
+            }
+        }
+        s
+    }
+}
+
+impl<T> Index<Vec2i> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: Vec2i) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("no cell at {:?}", p))
+    }
+}
+
+impl<T> FromIterator<(Vec2i, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2i, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (p, cell) in iter {
+            grid.insert(p, cell);
+        }
+        grid
+    }
+}
+
+impl<T> IntoIterator for SparseGrid<T> {
+    type Item = (Vec2i, T);
+    type IntoIter = std::collections::hash_map::IntoIter<Vec2i, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::read_map;
+
+    #[test]
+    fn sparse_grid_test() {
+        let map = read_map("#..\n.O.\n..@");
+        let mut grid = SparseGrid::from_grid(&map, |&c| c != '.');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.bounds(), Some((Vec2i::new(0, 0), Vec2i::new(2, 2))));
+        assert_eq!(grid.to_grid('.'), (Vec2i::new(0, 0), map));
+
+        assert!(grid.move_cell(Vec2i::new(2, 2), Vec2i::new(-1, 1)));
+        assert!(!grid.move_cell(Vec2i::new(2, 2), Vec2i::new(0, 0)));
+        assert_eq!(grid[Vec2i::new(-1, 1)], '@');
+        assert_eq!(grid.bounds(), Some((Vec2i::new(-1, 0), Vec2i::new(1, 1))));
+        assert_eq!(grid.render('.'), ".#.\n@.O\n");
+        let (min, dense) = grid.to_grid('.');
+        assert_eq!(min, Vec2i::new(-1, 0));
+        assert_eq!(dense[Vec2i::new(0, 1)], '@');
+
+        let region: Vec<_> = grid.region(Vec2i::new(0, 0), Vec2i::new(5, 5)).collect();
+        assert_eq!(
+            region,
+            vec![(Vec2i::new(0, 0), &'#'), (Vec2i::new(1, 1), &'O')]
+        );
+
+        grid.remove(Vec2i::new(0, 0));
+        grid.remove(Vec2i::new(1, 1));
+        grid.remove(Vec2i::new(-1, 1));
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render('.'), "");
+    }
+}
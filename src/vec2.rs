@@ -0,0 +1,341 @@
+use std::{
+    fmt,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
+};
+
+use crate::vecn::{VecN, Vector};
+
+/// Signed integer types usable as `Vec2` coordinates.
+pub trait Coord:
+    Copy
+    + Default
+    + Ord
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + From<i8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, Hash, Eq, Ord, PartialOrd)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+pub type Vec2i = Vec2<i32>;
+pub type Vec2l = Vec2<i64>;
+
+impl<T: Coord> Vec2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Moves `n` steps in direction `dir`.
+    pub fn step<D: Into<Vec2<T>>>(self, dir: D, n: T) -> Self {
+        self + dir.into() * n
+    }
+
+    /// The 8 surrounding positions, row by row.
+    pub fn neighbours_8(self) -> impl Iterator<Item = Self> {
+        const OFFSETS: [(i8, i8); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (-1, 0),
+            (1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ];
+        OFFSETS
+            .into_iter()
+            .map(move |(dx, dy)| self + Vec2::new(T::from(dx), T::from(dy)))
+    }
+
+    pub fn neighbours_4(self) -> impl Iterator<Item = Self> {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .map(move |(dx, dy)| self + Vec2::new(T::from(dx), T::from(dy)))
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        let d = self - other;
+        d.x.abs() + d.y.abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        let d = self - other;
+        d.x.abs().max(d.y.abs())
+    }
+
+    /// Quarter turn clockwise on screen, where y grows downwards.
+    pub fn rotate_cw(self) -> Self {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Quarter turn counter-clockwise on screen, where y grows downwards.
+    pub fn rotate_ccw(self) -> Self {
+        Vec2::new(self.y, -self.x)
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the 3D cross product, positive when `other` is
+    /// clockwise from `self` on screen.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn signum(self) -> Self {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Converts to a wider coordinate type, e.g. `Vec2i` to `Vec2l`.
+    pub fn cast<U: Coord + From<T>>(self) -> Vec2<U> {
+        Vec2::new(U::from(self.x), U::from(self.y))
+    }
+}
+
+impl<T: Coord> Vector for Vec2<T> {
+    fn neighbours_orthogonal(self) -> impl Iterator<Item = Self> {
+        self.neighbours_4()
+    }
+
+    fn neighbours_all(self) -> impl Iterator<Item = Self> {
+        self.neighbours_8()
+    }
+
+    fn min_components(self, other: Self) -> Self {
+        Vec2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    fn max_components(self, other: Self) -> Self {
+        Vec2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Copy> From<&(T, T)> for Vec2<T> {
+    fn from(&(x, y): &(T, T)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T: Copy> From<&Vec2<T>> for Vec2<T> {
+    fn from(other: &Vec2<T>) -> Self {
+        *other
+    }
+}
+
+impl<T> From<VecN<T, 2>> for Vec2<T> {
+    fn from(VecN([x, y]): VecN<T, 2>) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for VecN<T, 2> {
+    fn from(v: Vec2<T>) -> Self {
+        VecN([v.x, v.y])
+    }
+}
+
+impl<T: Coord, R: Into<Vec2<T>>> Add<R> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, other: R) -> Vec2<T> {
+        let rhs = other.into();
+        Vec2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Coord, R: Into<Vec2<T>>> Add<R> for &Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, other: R) -> Vec2<T> {
+        *self + other
+    }
+}
+
+impl<T: Coord, R: Into<Vec2<T>>> Sub<R> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: R) -> Vec2<T> {
+        let other = other.into();
+        Vec2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Coord, R: Into<Vec2<T>>> Sub<R> for &Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: R) -> Vec2<T> {
+        *self - other
+    }
+}
+
+impl<T: Coord, R: Into<Vec2<T>>> AddAssign<R> for Vec2<T> {
+    fn add_assign(&mut self, other: R) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord, R: Into<Vec2<T>>> SubAssign<R> for Vec2<T> {
+    fn sub_assign(&mut self, other: R) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coord> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Vec2<T> {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coord> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: T) -> Vec2<T> {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// Truncating division, like the integer division it is built on.
+impl<T: Coord> Div<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn div(self, rhs: T) -> Vec2<T> {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+/// Euclidean remainder per component, which wraps positions onto a
+/// `w` by `h` torus: `(p + v) % Vec2::new(w, h)`.
+impl<T: Coord> Rem<Vec2<T>> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn rem(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+}
+
+macro_rules! scalar_mul {
+    ($($t:ty),*) => {
+        $(impl Mul<Vec2<$t>> for $t {
+            type Output = Vec2<$t>;
+
+            fn mul(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                rhs * self
+            }
+        })*
+    };
+}
+
+scalar_mul!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec2i_test() {
+        let a = &Vec2i::new(1, 2);
+        assert_eq!(Vec2i::new(1, 2) + Vec2i::new(3, 4), Vec2i::new(4, 6));
+        assert_eq!(a + a, Vec2i::new(2, 4));
+        assert_eq!(Vec2i::new(3, 4) + a, Vec2i::new(4, 6));
+
+        assert_eq!(Vec2i::new(1, 2) - Vec2i::new(3, 5), Vec2i::new(-2, -3));
+        assert_eq!(a - Vec2i::new(3, 5), Vec2i::new(-2, -3));
+        assert_eq!(Vec2i::new(3, 5) - a, Vec2i::new(2, 3));
+
+        assert_eq!(5 * Vec2i::new(2, 3), Vec2i::new(10, 15));
+        assert_eq!(Vec2i::new(2, 3) * 5, Vec2i::new(10, 15));
+        assert_eq!(Vec2i::new(-7, 9) / 2, Vec2i::new(-3, 4));
+        assert_eq!(-Vec2i::new(2, -3), Vec2i::new(-2, 3));
+        assert_eq!(Vec2i::new(-1, 12) % Vec2i::new(11, 7), Vec2i::new(10, 5));
+    }
+
+    #[test]
+    fn geometry_test() {
+        let a = Vec2i::new(1, -2);
+        let b = Vec2i::new(4, 2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.dot(b), 0);
+        assert_eq!(a.cross(b), 10);
+        assert_eq!(Vec2i::new(-5, 0).signum(), Vec2i::new(-1, 0));
+
+        // Right turns into down on screen.
+        assert_eq!(Vec2i::new(1, 0).rotate_cw(), Vec2i::new(0, 1));
+        assert_eq!(a.rotate_cw().rotate_ccw(), a);
+        assert_eq!(a.rotate_cw().rotate_cw(), -a);
+
+        assert_eq!(a.neighbours_4().count(), 4);
+        let mut orthogonal: Vec<Vec2i> = a.neighbours_4().collect();
+        let mut generic: Vec<Vec2i> = VecN::from(a)
+            .neighbours_orthogonal()
+            .map(Vec2::from)
+            .collect();
+        orthogonal.sort();
+        generic.sort();
+        assert_eq!(orthogonal, generic);
+        assert_eq!(a.neighbours_8().count(), 8);
+        assert!(a.neighbours_8().all(|n| a.chebyshev(n) == 1));
+
+        let big: Vec2l = a.cast::<i64>() * 10_000_000_000;
+        assert_eq!(big, Vec2l::new(10_000_000_000, -20_000_000_000));
+        assert_eq!(a.to_string(), "(1, -2)");
+    }
+}
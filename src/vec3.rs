@@ -0,0 +1,238 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
+};
+
+use crate::{
+    vec2::Coord,
+    vecn::{VecN, Vector},
+};
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, Hash, Eq, Ord, PartialOrd)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+pub type Vec3i = Vec3<i32>;
+pub type Vec3l = Vec3<i64>;
+
+impl<T: Coord> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    /// The 6 neighbours that share a face.
+    pub fn neighbours_6(self) -> impl Iterator<Item = Self> {
+        self.neighbours_orthogonal()
+    }
+
+    /// The 26 neighbours that share at least a corner.
+    pub fn neighbours_26(self) -> impl Iterator<Item = Self> {
+        self.neighbours_all()
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        VecN::from(self).manhattan(other.into())
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        VecN::from(self).chebyshev(other.into())
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn signum(self) -> Self {
+        Vec3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    /// Converts to a wider coordinate type, e.g. `Vec3i` to `Vec3l`.
+    pub fn cast<U: Coord + From<T>>(self) -> Vec3<U> {
+        Vec3::new(U::from(self.x), U::from(self.y), U::from(self.z))
+    }
+}
+
+impl<T: Coord> Vector for Vec3<T> {
+    fn neighbours_orthogonal(self) -> impl Iterator<Item = Self> {
+        VecN::from(self).neighbours_orthogonal().map(Vec3::from)
+    }
+
+    fn neighbours_all(self) -> impl Iterator<Item = Self> {
+        VecN::from(self).neighbours_all().map(Vec3::from)
+    }
+
+    fn min_components(self, other: Self) -> Self {
+        Vec3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    fn max_components(self, other: Self) -> Self {
+        Vec3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T: Copy> From<&(T, T, T)> for Vec3<T> {
+    fn from(&(x, y, z): &(T, T, T)) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T: Copy> From<&Vec3<T>> for Vec3<T> {
+    fn from(other: &Vec3<T>) -> Self {
+        *other
+    }
+}
+
+impl<T> From<VecN<T, 3>> for Vec3<T> {
+    fn from(VecN([x, y, z]): VecN<T, 3>) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for VecN<T, 3> {
+    fn from(v: Vec3<T>) -> Self {
+        VecN([v.x, v.y, v.z])
+    }
+}
+
+impl<T: Coord, R: Into<Vec3<T>>> Add<R> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, other: R) -> Vec3<T> {
+        let rhs = other.into();
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coord, R: Into<Vec3<T>>> Sub<R> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, other: R) -> Vec3<T> {
+        let rhs = other.into();
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coord, R: Into<Vec3<T>>> AddAssign<R> for Vec3<T> {
+    fn add_assign(&mut self, other: R) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord, R: Into<Vec3<T>>> SubAssign<R> for Vec3<T> {
+    fn sub_assign(&mut self, other: R) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coord> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Vec3<T> {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coord> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: T) -> Vec3<T> {
+        Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: Coord> Div<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn div(self, rhs: T) -> Vec3<T> {
+        Vec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+/// Euclidean remainder per component, like `Vec2`.
+impl<T: Coord> Rem<Vec3<T>> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn rem(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.rem_euclid(rhs.x),
+            self.y.rem_euclid(rhs.y),
+            self.z.rem_euclid(rhs.z),
+        )
+    }
+}
+
+macro_rules! scalar_mul {
+    ($($t:ty),*) => {
+        $(impl Mul<Vec3<$t>> for $t {
+            type Output = Vec3<$t>;
+
+            fn mul(self, rhs: Vec3<$t>) -> Vec3<$t> {
+                rhs * self
+            }
+        })*
+    };
+}
+
+scalar_mul!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vecn::bounding_box;
+
+    #[test]
+    fn vec3i_test() {
+        let a = Vec3i::new(1, 2, 3);
+        let b = Vec3i::from((-2, 0, 5));
+        assert_eq!(a + b, Vec3i::new(-1, 2, 8));
+        assert_eq!(a - (1, 1, 1), Vec3i::new(0, 1, 2));
+        assert_eq!(2 * a, a * 2);
+        assert_eq!(-a / 2, Vec3i::new(0, -1, -1));
+        assert_eq!(b % Vec3i::new(3, 3, 3), Vec3i::new(1, 0, 2));
+        assert_eq!((a.manhattan(b), a.chebyshev(b)), (7, 3));
+        assert_eq!(a.dot(b), 13);
+        assert_eq!(
+            Vec3i::new(1, 0, 0).cross(Vec3i::new(0, 1, 0)),
+            Vec3i::new(0, 0, 1)
+        );
+        assert_eq!(a.to_string(), "(1, 2, 3)");
+
+        assert_eq!(a.neighbours_6().count(), 6);
+        assert!(a.neighbours_6().all(|n| a.manhattan(n) == 1));
+        assert_eq!(a.neighbours_26().count(), 26);
+
+        let (min, max) = bounding_box([a, b, Vec3i::default()]).unwrap();
+        assert_eq!((min, max), (Vec3i::new(-2, 0, 0), Vec3i::new(1, 2, 5)));
+    }
+}
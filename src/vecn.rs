@@ -0,0 +1,255 @@
+use std::{
+    fmt,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Rem, Sub, SubAssign},
+};
+
+use crate::vec2::Coord;
+
+/// Operations shared by integer vectors of every dimension, so that code
+/// like a flood fill works the same on `Vec2i`, `Vec3i` and `VecN`.
+pub trait Vector: Copy + Eq + Hash {
+    /// Neighbours one step along a single axis: 4 in 2D, 6 in 3D.
+    fn neighbours_orthogonal(self) -> impl Iterator<Item = Self>;
+
+    /// Neighbours that touch at least a corner: 8 in 2D, 26 in 3D.
+    fn neighbours_all(self) -> impl Iterator<Item = Self>;
+
+    /// Per-component minimum.
+    fn min_components(self, other: Self) -> Self;
+
+    /// Per-component maximum.
+    fn max_components(self, other: Self) -> Self;
+
+    /// Whether every component is within `min..=max`.
+    fn inside_box(self, min: Self, max: Self) -> bool {
+        self.max_components(min) == self && self.min_components(max) == self
+    }
+}
+
+/// Smallest box holding all `points`, as inclusive (min, max) corners.
+pub fn bounding_box<V: Vector>(points: impl IntoIterator<Item = V>) -> Option<(V, V)> {
+    points.into_iter().fold(None, |bounds, p| match bounds {
+        None => Some((p, p)),
+        Some((min, max)) => Some((min.min_components(p), max.max_components(p))),
+    })
+}
+
+/// Integer vector with `N` components.
+#[derive(Debug, PartialEq, Clone, Copy, Hash, Eq, Ord, PartialOrd)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+pub type VecNi<const N: usize> = VecN<i32, N>;
+
+impl<T: Coord, const N: usize> VecN<T, N> {
+    pub fn new(coords: [T; N]) -> Self {
+        VecN(coords)
+    }
+
+    fn zip_with(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        VecN(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    fn map(self, f: impl Fn(T) -> T) -> Self {
+        VecN(self.0.map(f))
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        (self - other)
+            .0
+            .into_iter()
+            .fold(T::default(), |sum, d| sum + d.abs())
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        (self - other)
+            .0
+            .into_iter()
+            .fold(T::default(), |max, d| max.max(d.abs()))
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        (0..N).fold(T::default(), |sum, i| sum + self.0[i] * other.0[i])
+    }
+
+    pub fn signum(self) -> Self {
+        self.map(T::signum)
+    }
+}
+
+impl<T: Coord, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        VecN([T::default(); N])
+    }
+}
+
+impl<T: Coord, const N: usize> Vector for VecN<T, N> {
+    fn neighbours_orthogonal(self) -> impl Iterator<Item = Self> {
+        (0..N).flat_map(move |axis| {
+            [-1, 1].map(|d| {
+                let mut v = self;
+                v.0[axis] += T::from(d);
+                v
+            })
+        })
+    }
+
+    /// Builds the whole 3^N cube first, which does allocate.
+    fn neighbours_all(self) -> impl Iterator<Item = Self> {
+        let mut cube = vec![self];
+        for axis in 0..N {
+            cube = cube
+                .into_iter()
+                .flat_map(|v| {
+                    [-1, 0, 1].map(|d| {
+                        let mut v = v;
+                        v.0[axis] += T::from(d);
+                        v
+                    })
+                })
+                .collect();
+        }
+        cube.into_iter().filter(move |&v| v != self)
+    }
+
+    fn min_components(self, other: Self) -> Self {
+        self.zip_with(other, T::min)
+    }
+
+    fn max_components(self, other: Self) -> Self {
+        self.zip_with(other, T::max)
+    }
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for VecN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(coords: [T; N]) -> Self {
+        VecN(coords)
+    }
+}
+
+impl<T: Copy, const N: usize> From<&VecN<T, N>> for VecN<T, N> {
+    fn from(other: &VecN<T, N>) -> Self {
+        *other
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.0[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.0[axis]
+    }
+}
+
+impl<T: Coord, R: Into<VecN<T, N>>, const N: usize> Add<R> for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    fn add(self, other: R) -> VecN<T, N> {
+        self.zip_with(other.into(), |a, b| a + b)
+    }
+}
+
+impl<T: Coord, R: Into<VecN<T, N>>, const N: usize> Sub<R> for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    fn sub(self, other: R) -> VecN<T, N> {
+        self.zip_with(other.into(), |a, b| a - b)
+    }
+}
+
+impl<T: Coord, R: Into<VecN<T, N>>, const N: usize> AddAssign<R> for VecN<T, N> {
+    fn add_assign(&mut self, other: R) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord, R: Into<VecN<T, N>>, const N: usize> SubAssign<R> for VecN<T, N> {
+    fn sub_assign(&mut self, other: R) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coord, const N: usize> Neg for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    fn neg(self) -> VecN<T, N> {
+        self.map(|c| -c)
+    }
+}
+
+impl<T: Coord, const N: usize> Mul<T> for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    fn mul(self, rhs: T) -> VecN<T, N> {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<T: Coord, const N: usize> Div<T> for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    fn div(self, rhs: T) -> VecN<T, N> {
+        self.map(|c| c / rhs)
+    }
+}
+
+/// Euclidean remainder per component, like `Vec2`.
+impl<T: Coord, const N: usize> Rem<VecN<T, N>> for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    fn rem(self, rhs: VecN<T, N>) -> VecN<T, N> {
+        self.zip_with(rhs, T::rem_euclid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vecn_test() {
+        let a = VecNi::new([1, 2, 3, 4]);
+        let b = VecN([4, 3, 2, 1]);
+        assert_eq!(a + b, VecN([5; 4]));
+        assert_eq!(a - b, VecN([-3, -1, 1, 3]));
+        assert_eq!(-a * 2, VecN([-2, -4, -6, -8]));
+        assert_eq!(a % VecN([3; 4]), VecN([1, 2, 0, 1]));
+        assert_eq!((a.manhattan(b), a.chebyshev(b), a.dot(b)), (8, 3, 20));
+        assert_eq!(a[3], 4);
+        assert_eq!(a.to_string(), "(1, 2, 3, 4)");
+
+        assert_eq!(a.neighbours_orthogonal().count(), 8);
+        assert_eq!(a.neighbours_all().count(), 80);
+        assert!(a.neighbours_all().all(|n| a.chebyshev(n) == 1));
+        assert!(VecNi::new([0]).neighbours_all().eq([VecN([-1]), VecN([1])]));
+    }
+
+    #[test]
+    fn bounding_box_test() {
+        let points = [VecN([3, -1]), VecN([0, 4]), VecN([2, 2])];
+        let (min, max) = bounding_box(points).unwrap();
+        assert_eq!((min, max), (VecN([0, -1]), VecN([3, 4])));
+        assert!(points.iter().all(|p| p.inside_box(min, max)));
+        assert!(!VecN([4, 0]).inside_box(min, max));
+        assert_eq!(bounding_box(Vec::<VecNi<2>>::new()), None);
+    }
+}